@@ -0,0 +1,129 @@
+//! RPC interface for the certificate pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_certificate_runtime_api::CertificateApi as CertificateRuntimeApi;
+use pallet_certificate_runtime_api::CertificateInfo;
+
+/// Certificate RPC methods.
+#[rpc(client, server)]
+pub trait CertificateApi<BlockHash, AccountId, BlockNumber> {
+	/// Lists every certificate sent by `account`, with its status.
+	#[method(name = "certificate_issuedBy")]
+	fn issued_by(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CertificateInfo<AccountId, BlockNumber>>>;
+
+	/// Lists every certificate sent to `account`, with its status.
+	#[method(name = "certificate_issuedTo")]
+	fn issued_to(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CertificateInfo<AccountId, BlockNumber>>>;
+
+	/// Checks whether the certificate `issuer` sent to `recipient` exists, has not been revoked
+	/// and has not expired.
+	#[method(name = "certificate_isValid")]
+	fn is_valid(
+		&self,
+		issuer: AccountId,
+		recipient: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+}
+
+/// Provides RPC methods to query the certificate pallet.
+pub struct Certificate<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Certificate<C, Block> {
+	/// Creates a new instance of the certificate RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(e: ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query certificates.",
+		Some(e.to_string()),
+	)
+}
+
+impl<C, Block, AccountId, BlockNumber>
+	CertificateApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber> for Certificate<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CertificateRuntimeApi<Block, AccountId, BlockNumber>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+	fn issued_by(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CertificateInfo<AccountId, BlockNumber>>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.certificates_issued_by(at_hash, account)
+			.map_err(runtime_error)
+	}
+
+	fn issued_to(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CertificateInfo<AccountId, BlockNumber>>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.certificates_issued_to(at_hash, account)
+			.map_err(runtime_error)
+	}
+
+	fn is_valid(
+		&self,
+		issuer: AccountId,
+		recipient: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.is_certificate_valid(at_hash, issuer, recipient)
+			.map_err(runtime_error)
+	}
+}
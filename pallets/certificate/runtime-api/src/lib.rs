@@ -0,0 +1,29 @@
+//! Runtime API definition for the certificate pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+pub use pallet_certificate::{
+	CertificateDetails, CertificateInfo, CertificateStatus, Revocation, RevocationReason,
+};
+
+sp_api::decl_runtime_apis! {
+	/// Lookups into the certificates sent through the certificate pallet.
+	pub trait CertificateApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Every certificate sent by `account`, with its status.
+		fn certificates_issued_by(account: AccountId) -> Vec<CertificateInfo<AccountId, BlockNumber>>;
+		/// Every certificate sent to `account` that it has not rejected, with its status.
+		fn certificates_issued_to(account: AccountId) -> Vec<CertificateInfo<AccountId, BlockNumber>>;
+		/// Whether the certificate `issuer` sent to `recipient` exists, has not been revoked and
+		/// has not expired.
+		fn is_certificate_valid(issuer: AccountId, recipient: AccountId) -> bool;
+	}
+}
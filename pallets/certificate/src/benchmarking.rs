@@ -5,8 +5,13 @@ use super::*;
 use crate::Pallet as Certificate;
 
 use frame_benchmarking::{account as benchmark_account, v2::*};
-use frame_support::{sp_runtime::traits::Bounded, traits::Currency};
-use frame_system::RawOrigin;
+use frame_support::{
+	sp_runtime::traits::Bounded,
+	traits::{Currency, Get},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use scale_info::prelude::vec;
 
 pub fn get_account<T: Config>(name: &'static str) -> T::AccountId {
 	let account: T::AccountId = benchmark_account(name, 0, 0);
@@ -17,6 +22,29 @@ pub fn get_origin<T: Config>(name: &'static str) -> RawOrigin<T::AccountId> {
 	RawOrigin::Signed(get_account::<T>(name))
 }
 
+pub fn get_claim<T: Config>() -> BoundedVec<u8, T::MaxClaimSize> {
+	BoundedVec::try_from(vec![0u8; T::MaxClaimSize::get() as usize]).unwrap()
+}
+
+/// Places `issuer` at the end of a delegation chain of `MaxChainDepth` links below a root
+/// authority, the longest chain `send_certificate` has to walk.
+pub fn authorize_issuer<T: Config>(issuer: &T::AccountId) {
+	let depth = T::MaxChainDepth::get();
+	if depth == 0 {
+		RootAuthorities::<T>::insert(issuer, true);
+		return;
+	}
+	let mut delegator: T::AccountId = benchmark_account("authority", 0, 0);
+	RootAuthorities::<T>::insert(&delegator, true);
+	for i in 0..depth - 1 {
+		let delegate: T::AccountId = benchmark_account("delegate", i, 0);
+		Certificate::<T>::delegate_issuer(RawOrigin::Signed(delegator).into(), delegate.clone())
+			.unwrap();
+		delegator = delegate;
+	}
+	Certificate::<T>::delegate_issuer(RawOrigin::Signed(delegator).into(), issuer.clone()).unwrap();
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -30,9 +58,10 @@ mod benchmarks {
 		let caller_account: T::AccountId = get_account::<T>("Spock");
 
 		T::Currency::make_free_balance_be(&caller_account, BalanceOf::<T>::max_value());
+		authorize_issuer::<T>(&caller_account);
 
 		#[extrinsic_call]
-		_(caller, target);
+		_(caller, target, get_claim::<T>(), 0, Some(BlockNumberFor::<T>::max_value()));
 
 		let caller_account_id: T::AccountId = get_account::<T>("Spock");
 		let target_account_id: T::AccountId = get_account::<T>("James");
@@ -47,16 +76,29 @@ mod benchmarks {
 		let caller_account: T::AccountId = get_account::<T>("Leonard");
 
 		T::Currency::make_free_balance_be(&caller_account, BalanceOf::<T>::max_value());
+		authorize_issuer::<T>(&caller_account);
 
 		// Generate a larger set of certificates.
 		for i in 0..10000 {
 			let target: T::AccountId = benchmark_account("target", i, 0);
-			Certificate::<T>::send_certificate(caller.clone().into(), target.clone())?;
+			Certificate::<T>::send_certificate(
+				caller.clone().into(),
+				target.clone(),
+				get_claim::<T>(),
+				0,
+				None,
+			)?;
 		}
 
 		let target = get_account::<T>("Montgomery");
 		#[extrinsic_call]
-		send_certificate(caller, target);
+		send_certificate(
+			caller,
+			target,
+			get_claim::<T>(),
+			0,
+			Some(BlockNumberFor::<T>::max_value()),
+		);
 
 		let caller_account_id: T::AccountId = get_account::<T>("Leonard");
 		let target_account_id: T::AccountId = get_account::<T>("Montgomery");
@@ -72,11 +114,18 @@ mod benchmarks {
 		let target = get_account::<T>("Montgomery");
 
 		T::Currency::make_free_balance_be(&caller_account, BalanceOf::<T>::max_value());
+		authorize_issuer::<T>(&caller_account);
 
-		Certificate::<T>::send_certificate(caller.clone().into(), target.clone())?;
+		Certificate::<T>::send_certificate(
+			caller.clone().into(),
+			target.clone(),
+			get_claim::<T>(),
+			0,
+			None,
+		)?;
 
 		#[extrinsic_call]
-		_(caller, target);
+		_(caller, target, RevocationReason::Unspecified);
 
 		let caller_account_id: T::AccountId = get_account::<T>("Leonard");
 		let target_account_id: T::AccountId = get_account::<T>("Montgomery");
@@ -95,17 +144,30 @@ mod benchmarks {
 		let caller_account: T::AccountId = get_account::<T>("Leonard");
 
 		T::Currency::make_free_balance_be(&caller_account, BalanceOf::<T>::max_value());
+		authorize_issuer::<T>(&caller_account);
 
 		// Generate a larger set of certificates.
 		for i in 0..10000 {
 			let target: T::AccountId = benchmark_account("target", i, 0);
-			Certificate::<T>::send_certificate(caller.clone().into(), target.clone())?;
+			Certificate::<T>::send_certificate(
+				caller.clone().into(),
+				target.clone(),
+				get_claim::<T>(),
+				0,
+				None,
+			)?;
 		}
 
 		let target = get_account::<T>("Montgomery");
-		Certificate::<T>::send_certificate(caller.clone().into(), target.clone())?;
+		Certificate::<T>::send_certificate(
+			caller.clone().into(),
+			target.clone(),
+			get_claim::<T>(),
+			0,
+			None,
+		)?;
 		#[extrinsic_call]
-		revoke_certificate(caller, target);
+		revoke_certificate(caller, target, RevocationReason::Unspecified);
 
 		let caller_account_id: T::AccountId = get_account::<T>("Leonard");
 		let target_account_id: T::AccountId = get_account::<T>("Montgomery");
@@ -118,5 +180,98 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn add_root_authority() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PrivilegedOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let authority = get_account::<T>("Anakin");
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, authority.clone());
+
+		assert!(RootAuthorities::<T>::get(authority));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_root_authority() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PrivilegedOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let authority = get_account::<T>("Anakin");
+		RootAuthorities::<T>::insert(&authority, true);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, authority.clone());
+
+		assert!(!RootAuthorities::<T>::get(authority));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn delegate_issuer(n: Linear<0, 4>) -> Result<(), BenchmarkError> {
+		// Build a chain of `n` delegations below the root so the caller's chain is `n` links long.
+		let mut delegator = get_account::<T>("Anakin");
+		RootAuthorities::<T>::insert(&delegator, true);
+		for i in 0..n {
+			let issuer: T::AccountId = benchmark_account("issuer", i, 0);
+			Certificate::<T>::delegate_issuer(
+				RawOrigin::Signed(delegator.clone()).into(),
+				issuer.clone(),
+			)?;
+			delegator = issuer;
+		}
+		let issuer = get_account::<T>("Luke");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator), issuer.clone());
+
+		assert!(matches!(Certificate::<T>::verify_chain(&issuer), Ok(depth) if depth == n + 1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_issuer() -> Result<(), BenchmarkError> {
+		let caller = get_origin::<T>("Anakin");
+		let authority = get_account::<T>("Anakin");
+		let issuer = get_account::<T>("Luke");
+		RootAuthorities::<T>::insert(&authority, true);
+		Certificate::<T>::delegate_issuer(caller.clone().into(), issuer.clone())?;
+
+		#[extrinsic_call]
+		_(caller, issuer.clone());
+
+		assert!(matches!(Certificate::<T>::verify_chain(&issuer), Err(Error::<T>::RevokedLink)));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reject_certificate() -> Result<(), BenchmarkError> {
+		let issuer = get_origin::<T>("Anakin");
+		let issuer_account: T::AccountId = get_account::<T>("Anakin");
+		let caller = get_origin::<T>("Luke");
+		let caller_account: T::AccountId = get_account::<T>("Luke");
+
+		T::Currency::make_free_balance_be(&issuer_account, BalanceOf::<T>::max_value());
+		authorize_issuer::<T>(&issuer_account);
+		Certificate::<T>::send_certificate(
+			issuer.into(),
+			caller_account.clone(),
+			get_claim::<T>(),
+			0,
+			None,
+		)?;
+
+		#[extrinsic_call]
+		_(caller, issuer_account.clone());
+
+		assert!(Revocations::<T>::contains_key(issuer_account, caller_account));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Certificate, crate::mock::new_test_ext(), crate::mock::Test);
 }
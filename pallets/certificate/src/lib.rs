@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -11,6 +13,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
@@ -18,9 +21,11 @@ const CERTIFICATE_EXISTS: bool = true;
 
 #[frame_support::pallet]
 pub mod pallet {
+	use alloc::vec::Vec;
 	use frame_support::{
 		dispatch::DispatchResult,
 		pallet_prelude::*,
+		sp_runtime::traits::{Hash, Saturating, Zero},
 		traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
 	};
 	use frame_system::pallet_prelude::*;
@@ -44,9 +49,98 @@ pub mod pallet {
 		type LockId: Get<LockIdentifier>;
 		/// The price of a certificate lock.
 		type LockPrice: Get<u32>;
+		/// The maximum size of a certificate's claim.
+		type MaxClaimSize: Get<u32>;
+		/// Privileged origin that can add or remove root certificate authorities.
+		type PrivilegedOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The maximum number of delegation links between an issuer and a root authority.
+		type MaxChainDepth: Get<u32>;
+	}
+
+	/// The contents of a certificate sent from one account to another.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct CertificateDetails<Claim, BlockNumber> {
+		/// What the issuer asserts about the recipient.
+		pub claim: Claim,
+		/// Identifies the schema the claim is encoded with.
+		pub schema_id: u32,
+		/// The block in which the certificate was issued.
+		pub issued_at: BlockNumber,
+		/// The block from which the certificate is no longer valid, if any.
+		pub expires_at: Option<BlockNumber>,
 	}
 
+	pub type CertificateDetailsOf<T> =
+		CertificateDetails<BoundedVec<u8, <T as Config>::MaxClaimSize>, BlockNumberFor<T>>;
+
+	/// A link from an intermediate issuer to the authority that delegated it.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Delegation<AccountId> {
+		/// The root authority or intermediate issuer that delegated the issuer.
+		pub delegator: AccountId,
+		/// Whether the delegator has since revoked the link.
+		pub revoked: bool,
+	}
+
+	/// Why a certificate was revoked.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum RevocationReason {
+		/// No reason was given.
+		Unspecified,
+		/// The claim no longer holds or was issued in error.
+		ClaimInvalid,
+		/// The certificate was replaced by a newer one.
+		Superseded,
+		/// The issuer's keys or account were compromised.
+		IssuerCompromised,
+		/// The recipient refused the certificate.
+		RejectedByRecipient,
+	}
+
+	/// A revocation list entry.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct Revocation<BlockNumber> {
+		/// Why the certificate was revoked.
+		pub reason: RevocationReason,
+		/// The block in which the certificate was revoked.
+		pub revoked_at: BlockNumber,
+	}
+
+	/// The state of a certificate, as returned by the runtime API.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum CertificateStatus<BlockNumber> {
+		/// The certificate has neither been revoked nor expired.
+		Valid,
+		/// The certificate reached its expiry block.
+		Expired,
+		/// The certificate was revoked or rejected. Certificates revoked before the revocation
+		/// list existed carry no entry.
+		Revoked(Option<Revocation<BlockNumber>>),
+	}
+
+	/// Everything recorded about a single certificate, as returned by the runtime API.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct CertificateInfo<AccountId, BlockNumber> {
+		/// The account that sent the certificate.
+		pub issuer: AccountId,
+		/// The account the certificate was sent to.
+		pub recipient: AccountId,
+		/// The claim, schema and validity period, if the certificate is still held.
+		pub details: Option<CertificateDetails<Vec<u8>, BlockNumber>>,
+		/// Whether the certificate is valid, expired or revoked.
+		pub status: CertificateStatus<BlockNumber>,
+	}
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -62,15 +156,97 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn certificate)]
+	/// The claim, schema and validity period of each certificate, keyed by issuer and recipient.
+	pub type Certificates<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		CertificateDetailsOf<T>,
+	>;
+
+	#[pallet::storage]
+	/// Reverse index of `CertificateList`, mapping each recipient to the issuers that sent it
+	/// certificates.
+	pub type CertificatesByRecipient<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn certificate_deposit)]
+	/// The amount each outstanding certificate contributes to its issuer's lock.
+	pub type CertificateDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn total_locked)]
+	/// The total amount locked by each issuer for its outstanding certificates.
+	pub type TotalLocked<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn revocation)]
+	/// The revocation list: why and when each revoked certificate was revoked, keyed by issuer
+	/// and recipient.
+	pub type Revocations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		Revocation<BlockNumberFor<T>>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_root_authority)]
+	/// The root certificate authorities every delegation chain must end at.
+	pub type RootAuthorities<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn delegation)]
+	/// Maps each intermediate issuer to the authority that delegated it.
+	pub type Delegations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Delegation<T::AccountId>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// A `certificate` was sent.
-		CertificateSent(T::AccountId, T::AccountId),
-		/// A `certificate` was revoked.
-		CertificateRevoked(T::AccountId, T::AccountId),
+		/// A `certificate` was sent. Contains the recipient, the issuer and the hash of the claim.
+		CertificateSent(T::AccountId, T::AccountId, T::Hash),
+		/// A `certificate` was revoked. Contains the recipient, the issuer and the hash of the
+		/// claim.
+		CertificateRevoked(T::AccountId, T::AccountId, T::Hash),
 		CertificateLock(<T as frame_system::Config>::AccountId, BalanceOf<T>),
 		CertificateUnlock(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+		/// A root certificate authority was registered.
+		RootAuthorityAdded(T::AccountId),
+		/// A root certificate authority was removed.
+		RootAuthorityRemoved(T::AccountId),
+		/// An intermediate issuer was delegated. Contains the issuer and the delegator.
+		IssuerDelegated(T::AccountId, T::AccountId),
+		/// An intermediate issuer's delegation was revoked. Contains the issuer and the
+		/// delegator.
+		IssuerRevoked(T::AccountId, T::AccountId),
+		/// A recipient rejected a `certificate`. Contains the recipient, the issuer and the hash
+		/// of the claim.
+		CertificateRejected(T::AccountId, T::AccountId, T::Hash),
 	}
 
 	#[pallet::error]
@@ -80,34 +256,197 @@ pub mod pallet {
 		/// The certificate already exists.
 		CertificateExists,
 		InsufficientBalance,
+		/// The requested expiry block is not in the future.
+		ExpiryInPast,
+		/// The account is already a root authority.
+		RootAuthorityExists,
+		/// The account is not a root authority.
+		NotRootAuthority,
+		/// The account is neither a root authority nor a delegated issuer.
+		UnknownIssuer,
+		/// The issuer already holds an active delegation or is a root authority.
+		IssuerAlreadyDelegated,
+		/// An account cannot delegate to itself.
+		CannotDelegateSelf,
+		/// The caller did not delegate this issuer.
+		NotDelegator,
+		/// A link in the delegation chain has been revoked.
+		RevokedLink,
+		/// The delegation chain is longer than `MaxChainDepth`.
+		ChainTooLong,
+		/// The certificate has already been revoked or rejected.
+		CertificateAlreadyRevoked,
+		/// No certificate was sent to the caller by this issuer.
+		CertificateNotFound,
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether the certificate `issuer` sent to `recipient` exists, has not been revoked and
+		/// has not expired.
+		pub fn is_certificate_valid(issuer: &T::AccountId, recipient: &T::AccountId) -> bool {
+			if !<CertificateList<T>>::get(issuer, recipient) {
+				return false;
+			}
+			let expires_at =
+				<Certificates<T>>::get(issuer, recipient).and_then(|details| details.expires_at);
+			match expires_at {
+				Some(expires_at) => expires_at > <frame_system::Pallet<T>>::block_number(),
+				None => true,
+			}
+		}
+
+		/// Returns everything recorded about the certificate `issuer` sent to `recipient`, if one
+		/// was ever sent.
+		pub fn certificate_info(
+			issuer: &T::AccountId,
+			recipient: &T::AccountId,
+		) -> Option<CertificateInfo<T::AccountId, BlockNumberFor<T>>> {
+			if !<CertificateList<T>>::contains_key(issuer, recipient) {
+				return None;
+			}
+			let status = if !<CertificateList<T>>::get(issuer, recipient) {
+				CertificateStatus::Revoked(<Revocations<T>>::get(issuer, recipient))
+			} else if Self::is_certificate_valid(issuer, recipient) {
+				CertificateStatus::Valid
+			} else {
+				CertificateStatus::Expired
+			};
+			let details =
+				<Certificates<T>>::get(issuer, recipient).map(|details| CertificateDetails {
+					claim: details.claim.into_inner(),
+					schema_id: details.schema_id,
+					issued_at: details.issued_at,
+					expires_at: details.expires_at,
+				});
+			Some(CertificateInfo {
+				issuer: issuer.clone(),
+				recipient: recipient.clone(),
+				details,
+				status,
+			})
+		}
+
+		/// Lists every certificate sent by `issuer`, including revoked and expired ones.
+		pub fn certificates_issued_by(
+			issuer: &T::AccountId,
+		) -> Vec<CertificateInfo<T::AccountId, BlockNumberFor<T>>> {
+			<CertificateList<T>>::iter_key_prefix(issuer)
+				.filter_map(|recipient| Self::certificate_info(issuer, &recipient))
+				.collect()
+		}
+
+		/// Lists every certificate sent to `recipient`, including revoked and expired ones but not
+		/// the ones `recipient` rejected.
+		pub fn certificates_issued_to(
+			recipient: &T::AccountId,
+		) -> Vec<CertificateInfo<T::AccountId, BlockNumberFor<T>>> {
+			<CertificatesByRecipient<T>>::iter_key_prefix(recipient)
+				.filter_map(|issuer| Self::certificate_info(&issuer, recipient))
+				.collect()
+		}
+
+		/// Walks the delegation links from `issuer` up to a root authority, returning the number of
+		/// links traversed. Fails if the chain does not end at a root authority, contains a
+		/// revoked link or is longer than `MaxChainDepth`. Only issuers with a valid chain may
+		/// send certificates or delegate further issuers.
+		pub fn verify_chain(issuer: &T::AccountId) -> Result<u32, Error<T>> {
+			let mut current = issuer.clone();
+			let mut depth = 0u32;
+			while !<RootAuthorities<T>>::get(&current) {
+				let delegation =
+					<Delegations<T>>::get(&current).ok_or(Error::<T>::UnknownIssuer)?;
+				ensure!(!delegation.revoked, Error::<T>::RevokedLink);
+				depth += 1;
+				ensure!(depth <= T::MaxChainDepth::get(), Error::<T>::ChainTooLong);
+				current = delegation.delegator;
+			}
+			Ok(depth)
+		}
+
+		/// Marks the certificate `issuer` sent to `recipient` as revoked, releases its share of
+		/// the issuer's lock and records the revocation. Returns the hash of the certificate's
+		/// claim.
+		fn do_revoke(
+			issuer: &T::AccountId,
+			recipient: &T::AccountId,
+			reason: RevocationReason,
+		) -> T::Hash {
+			let deposit = <CertificateDeposits<T>>::take(issuer, recipient);
+			let total = Self::total_locked(issuer).saturating_sub(deposit);
+			Self::set_total_lock(issuer, total);
+			Self::deposit_event(Event::CertificateUnlock(
+				issuer.clone(),
+				T::Currency::free_balance(issuer),
+			));
+
+			<CertificateList<T>>::insert(issuer, recipient, !CERTIFICATE_EXISTS);
+			<Revocations<T>>::insert(
+				issuer,
+				recipient,
+				Revocation { reason, revoked_at: <frame_system::Pallet<T>>::block_number() },
+			);
+
+			<Certificates<T>>::take(issuer, recipient)
+				.map(|details| T::Hashing::hash(&details.claim))
+				.unwrap_or_default()
+		}
+
+		/// Records `total` as the amount locked by `who` and updates the lock to match, removing
+		/// it once nothing is left to hold.
+		pub(crate) fn set_total_lock(who: &T::AccountId, total: BalanceOf<T>) {
+			if total.is_zero() {
+				T::Currency::remove_lock(T::LockId::get(), who);
+				<TotalLocked<T>>::remove(who);
+			} else {
+				T::Currency::set_lock(T::LockId::get(), who, total, WithdrawReasons::all());
+				<TotalLocked<T>>::insert(who, total);
+			}
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates an on-chain event with a Certificate payload defined as part of the transaction
-		/// and commits the details to storage.
+		/// and commits the details to storage. The certificate carries `claim`, encoded according
+		/// to `schema_id`, and stops being valid at `expires_at` if given. The sender must be a root
+		/// authority or an issuer delegated by one through a chain of unrevoked links.
 		#[pallet::weight(T::WeightInfo::send_certificate())]
 		#[pallet::call_index(0)]
-		pub fn send_certificate(origin: OriginFor<T>, recipient: T::AccountId) -> DispatchResult {
+		pub fn send_certificate(
+			origin: OriginFor<T>,
+			recipient: T::AccountId,
+			claim: BoundedVec<u8, T::MaxClaimSize>,
+			schema_id: u32,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			if T::Currency::total_balance(&who) < T::Currency::minimum_balance() {
 				return Err(Error::<T>::InsufficientBalance.into());
 			}
+			Self::verify_chain(&who)?;
 
 			ensure!(
 				!CertificateList::<T>::contains_key(&who, &recipient),
 				Error::<T>::CertificateExists
 			);
-			// Insert a placeholder value into storage - if the pair (who, recipient) exists, we
-			// know there's a certificate present for the pair, regardless of value.
-			T::Currency::set_lock(T::LockId::get(), &who, 10u32.into(), WithdrawReasons::all());
+			let issued_at = <frame_system::Pallet<T>>::block_number();
+			if let Some(expires_at) = expires_at {
+				ensure!(expires_at > issued_at, Error::<T>::ExpiryInPast);
+			}
+			let deposit: BalanceOf<T> = T::LockPrice::get().into();
+			let total = Self::total_locked(&who).saturating_add(deposit);
+			ensure!(T::Currency::free_balance(&who) >= total, Error::<T>::InsufficientBalance);
+			Self::set_total_lock(&who, total);
+			<CertificateDeposits<T>>::insert(&who, &recipient, deposit);
 
 			Self::deposit_event(Event::CertificateLock(
 				who.clone(),
 				T::Currency::free_balance(&who),
 			));
 
+			// Insert a placeholder value into storage - if the pair (who, recipient) exists, we
+			// know there's a certificate present for the pair, regardless of value.
 			<CertificateList<T>>::try_mutate(
 				&who,
 				recipient.clone(),
@@ -117,16 +456,29 @@ pub mod pallet {
 				},
 			)?;
 
-			Self::deposit_event(Event::CertificateSent(recipient, who));
+			<CertificatesByRecipient<T>>::insert(&recipient, &who, ());
+
+			let claim_hash = T::Hashing::hash(&claim);
+			<Certificates<T>>::insert(
+				&who,
+				&recipient,
+				CertificateDetails { claim, schema_id, issued_at, expires_at },
+			);
+
+			Self::deposit_event(Event::CertificateSent(recipient, who, claim_hash));
 
 			Ok(())
 		}
 
 		#[pallet::weight(T::WeightInfo::revoke_certificate())]
 		#[pallet::call_index(1)]
-		/// Revokes the identity with ID number identity_id, as long as the identity is owned by
-		/// origin.
-		pub fn revoke_certificate(origin: OriginFor<T>, recipient: T::AccountId) -> DispatchResult {
+		/// Revokes the certificate origin sent to `recipient`, recording `reason` in the
+		/// revocation list.
+		pub fn revoke_certificate(
+			origin: OriginFor<T>,
+			recipient: T::AccountId,
+			reason: RevocationReason,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			if T::Currency::total_balance(&who) < T::Currency::minimum_balance() {
@@ -137,23 +489,110 @@ pub mod pallet {
 				CertificateList::<T>::contains_key(&who, &recipient),
 				Error::<T>::CertificateNotOwned
 			);
+			ensure!(
+				CertificateList::<T>::get(&who, &recipient),
+				Error::<T>::CertificateAlreadyRevoked
+			);
 
-			T::Currency::remove_lock(T::LockId::get(), &who);
-			Self::deposit_event(Event::CertificateUnlock(
-				who.clone(),
-				T::Currency::free_balance(&who),
-			));
+			let claim_hash = Self::do_revoke(&who, &recipient, reason);
+			Self::deposit_event(Event::CertificateRevoked(recipient, who, claim_hash));
 
-			<CertificateList<T>>::try_mutate(
-				&who,
-				recipient.clone(),
-				|certificate| -> DispatchResult {
-					*certificate = !CERTIFICATE_EXISTS;
-					Ok(())
-				},
-			)?;
+			Ok(())
+		}
+
+		/// Registers `authority` as a root certificate authority.
+		#[pallet::weight(T::WeightInfo::add_root_authority())]
+		#[pallet::call_index(2)]
+		pub fn add_root_authority(origin: OriginFor<T>, authority: T::AccountId) -> DispatchResult {
+			T::PrivilegedOrigin::ensure_origin(origin)?;
+			ensure!(!<RootAuthorities<T>>::get(&authority), Error::<T>::RootAuthorityExists);
+
+			<RootAuthorities<T>>::insert(&authority, true);
+			Self::deposit_event(Event::RootAuthorityAdded(authority));
+
+			Ok(())
+		}
+
+		/// Removes `authority` from the root certificate authorities. Chains ending at it no
+		/// longer verify.
+		#[pallet::weight(T::WeightInfo::remove_root_authority())]
+		#[pallet::call_index(3)]
+		pub fn remove_root_authority(
+			origin: OriginFor<T>,
+			authority: T::AccountId,
+		) -> DispatchResult {
+			T::PrivilegedOrigin::ensure_origin(origin)?;
+			ensure!(<RootAuthorities<T>>::get(&authority), Error::<T>::NotRootAuthority);
+
+			<RootAuthorities<T>>::remove(&authority);
+			Self::deposit_event(Event::RootAuthorityRemoved(authority));
+
+			Ok(())
+		}
+
+		/// Delegates `issuer` as an intermediate issuer under origin, which must itself be a root
+		/// authority or hold a valid delegation chain.
+		#[pallet::weight(T::WeightInfo::delegate_issuer(T::MaxChainDepth::get()))]
+		#[pallet::call_index(4)]
+		pub fn delegate_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who != issuer, Error::<T>::CannotDelegateSelf);
+
+			let depth = Self::verify_chain(&who)?;
+			ensure!(depth < T::MaxChainDepth::get(), Error::<T>::ChainTooLong);
+			ensure!(!<RootAuthorities<T>>::get(&issuer), Error::<T>::IssuerAlreadyDelegated);
+			if let Some(delegation) = <Delegations<T>>::get(&issuer) {
+				ensure!(delegation.revoked, Error::<T>::IssuerAlreadyDelegated);
+			}
+
+			<Delegations<T>>::insert(
+				&issuer,
+				Delegation { delegator: who.clone(), revoked: false },
+			);
+			Self::deposit_event(Event::IssuerDelegated(issuer, who));
+
+			Ok(())
+		}
+
+		/// Revokes the delegation origin gave to `issuer`. Chains passing through the issuer no
+		/// longer verify.
+		#[pallet::weight(T::WeightInfo::revoke_issuer())]
+		#[pallet::call_index(5)]
+		pub fn revoke_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Delegations<T>>::try_mutate(&issuer, |delegation| -> DispatchResult {
+				let delegation = delegation.as_mut().ok_or(Error::<T>::UnknownIssuer)?;
+				ensure!(delegation.delegator == who, Error::<T>::NotDelegator);
+				ensure!(!delegation.revoked, Error::<T>::RevokedLink);
+				delegation.revoked = true;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::IssuerRevoked(issuer, who));
+
+			Ok(())
+		}
+
+		/// Refuses the certificate `issuer` sent to origin. The certificate is added to the
+		/// revocation list, it is no longer listed among origin's certificates and `issuer` cannot
+		/// send origin another one.
+		#[pallet::weight(T::WeightInfo::reject_certificate())]
+		#[pallet::call_index(6)]
+		pub fn reject_certificate(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				CertificateList::<T>::contains_key(&issuer, &who),
+				Error::<T>::CertificateNotFound
+			);
+			ensure!(
+				CertificateList::<T>::get(&issuer, &who),
+				Error::<T>::CertificateAlreadyRevoked
+			);
 
-			Self::deposit_event(Event::CertificateRevoked(recipient, who));
+			let claim_hash = Self::do_revoke(&issuer, &who, RevocationReason::RejectedByRecipient);
+			<CertificatesByRecipient<T>>::remove(&who, &issuer);
+			Self::deposit_event(Event::CertificateRejected(who, issuer, claim_hash));
 
 			Ok(())
 		}
@@ -0,0 +1,151 @@
+//! Storage migrations for the certificate pallet.
+
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+use alloc::collections::BTreeMap;
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+
+use crate::{Config, Pallet};
+
+pub mod v1 {
+	use super::*;
+	use crate::{CertificateList, CertificatesByRecipient};
+
+	/// Builds the `CertificatesByRecipient` index from the certificates already in
+	/// `CertificateList`, revoked ones included, so recipients can look up every certificate
+	/// sent before the index existed.
+	pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut certificates = 0u64;
+			for (issuer, recipient) in CertificateList::<T>::iter_keys() {
+				certificates += 1;
+				CertificatesByRecipient::<T>::insert(&recipient, &issuer, ());
+			}
+			T::DbWeight::get().reads_writes(certificates, certificates)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((CertificateList::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				CertificateList::<T>::iter_keys().count() as u32 == count,
+				"certificates were lost"
+			);
+			for (issuer, recipient) in CertificateList::<T>::iter_keys() {
+				ensure!(
+					CertificatesByRecipient::<T>::contains_key(&recipient, &issuer),
+					"recipient index is incomplete"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrates the certificate pallet from storage version 0 to 1.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v2 {
+	use super::*;
+	use crate::{CertificateDeposits, CertificateList, TotalLocked};
+	use frame_support::{
+		sp_runtime::traits::{Saturating, Zero},
+		traits::Currency,
+	};
+
+	type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Records a `LockPrice` deposit for every outstanding certificate sent before deposits were
+	/// tracked, then recomputes `TotalLocked` and the lock of each issuer from the deposits of
+	/// its outstanding certificates. Before deposits existed, each certificate set the issuer's
+	/// lock to a fixed amount and any revocation removed it, so the old lock says nothing about
+	/// how many certificates it covers.
+	pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut totals: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+			let mut certificates = 0u64;
+			let mut recorded = 0u64;
+			for (issuer, recipient, outstanding) in CertificateList::<T>::iter() {
+				certificates += 1;
+				let total = totals.entry(issuer.clone()).or_default();
+				if !outstanding {
+					continue;
+				}
+				let mut deposit = CertificateDeposits::<T>::get(&issuer, &recipient);
+				if deposit.is_zero() {
+					deposit = T::LockPrice::get().into();
+					CertificateDeposits::<T>::insert(&issuer, &recipient, deposit);
+					recorded += 1;
+				}
+				total.saturating_accrue(deposit);
+			}
+
+			// Setting the lock touches the issuer's locks and account as well as `TotalLocked`.
+			let issuers = totals.len() as u64;
+			for (issuer, total) in totals {
+				Pallet::<T>::set_total_lock(&issuer, total);
+			}
+
+			let reads = certificates.saturating_add(issuers).saturating_mul(2);
+			let writes = recorded.saturating_add(issuers.saturating_mul(3));
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((CertificateList::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				CertificateList::<T>::iter_keys().count() as u32 == count,
+				"certificates were lost"
+			);
+			let mut totals: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+			for (issuer, recipient, outstanding) in CertificateList::<T>::iter() {
+				if outstanding {
+					let deposit = CertificateDeposits::<T>::get(&issuer, &recipient);
+					ensure!(!deposit.is_zero(), "outstanding certificate has no deposit");
+					totals.entry(issuer).or_default().saturating_accrue(deposit);
+				}
+			}
+			for (issuer, total) in totals {
+				ensure!(TotalLocked::<T>::get(&issuer) == total, "issuer total is wrong");
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrates the certificate pallet from storage version 1 to 2.
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		InnerMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
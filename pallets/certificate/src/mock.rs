@@ -80,6 +80,9 @@ impl pallet_certificate::Config for Test {
 	type Currency = Balances;
 	type LockId = MockLockIdentifier;
 	type LockPrice = ConstU32<10>;
+	type MaxClaimSize = ConstU32<1024>;
+	type PrivilegedOrigin = frame_system::EnsureRoot<u64>;
+	type MaxChainDepth = ConstU32<5>;
 }
 
 // Build genesis storage according to the mock runtime.
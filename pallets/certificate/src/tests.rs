@@ -1,13 +1,45 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use crate::{
+	migrations, mock::*, CertificateDetails, CertificateInfo, CertificateList, CertificateStatus,
+	CertificatesByRecipient, Error, Revocation, RevocationReason,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		Currency, GetStorageVersion, LockableCurrency, OnRuntimeUpgrade, StorageVersion,
+		WithdrawReasons,
+	},
+	BoundedVec,
+};
+use sp_core::{ConstU32, H256};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn claim() -> BoundedVec<u8, ConstU32<1024>> {
+	BoundedVec::try_from("verified".as_bytes().to_vec()).unwrap()
+}
+
+fn claim_hash() -> H256 {
+	BlakeTwo256::hash(&claim())
+}
+
+/// Registers `issuer` as a root authority so it may send certificates.
+fn authorize(issuer: u64) {
+	assert_ok!(CertificateModule::add_root_authority(RuntimeOrigin::root(), issuer));
+}
 
 #[test]
 fn test_send_certificate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateSent(1, 1).into());
+		authorize(1);
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			1,
+			claim(),
+			0,
+			None
+		));
+		System::assert_last_event(crate::Event::CertificateSent(1, 1, claim_hash()).into());
 		assert_eq!(CertificateModule::certificate_list(1, 1), true);
 	});
 }
@@ -17,10 +49,17 @@ fn test_send_existing_certificate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateSent(1, 1).into());
+		authorize(1);
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			1,
+			claim(),
+			0,
+			None
+		));
+		System::assert_last_event(crate::Event::CertificateSent(1, 1, claim_hash()).into());
 		assert_noop!(
-			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1),
+			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1, claim(), 0, None),
 			Error::<Test>::CertificateExists
 		);
 		assert_eq!(CertificateModule::certificate_list(1, 1), true);
@@ -32,10 +71,21 @@ fn test_revoke_certificate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateSent(1, 1).into());
-		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateRevoked(1, 1).into());
+		authorize(1);
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			1,
+			claim(),
+			0,
+			None
+		));
+		System::assert_last_event(crate::Event::CertificateSent(1, 1, claim_hash()).into());
+		assert_ok!(CertificateModule::revoke_certificate(
+			RuntimeOrigin::signed(1),
+			1,
+			RevocationReason::Unspecified
+		));
+		System::assert_last_event(crate::Event::CertificateRevoked(1, 1, claim_hash()).into());
 		assert_eq!(CertificateModule::certificate_list(1, 1), false);
 	});
 }
@@ -45,13 +95,481 @@ fn test_try_revoke_unowned_certificate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
+		authorize(1);
 		let _ = Balances::deposit_creating(&2, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateSent(1, 1).into());
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			1,
+			claim(),
+			0,
+			None
+		));
+		System::assert_last_event(crate::Event::CertificateSent(1, 1, claim_hash()).into());
 		assert_noop!(
-			CertificateModule::revoke_certificate(RuntimeOrigin::signed(2), 1),
+			CertificateModule::revoke_certificate(
+				RuntimeOrigin::signed(2),
+				1,
+				RevocationReason::Unspecified
+			),
 			Error::<Test>::CertificateNotOwned
 		);
 		assert_eq!(CertificateModule::certificate_list(1, 1), true);
 	});
 }
+
+#[test]
+fn test_certificate_details() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		authorize(1);
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			claim(),
+			7,
+			Some(5)
+		));
+		System::assert_last_event(crate::Event::CertificateSent(2, 1, claim_hash()).into());
+		assert_eq!(
+			CertificateModule::certificate(1, 2),
+			Some(CertificateDetails {
+				claim: claim(),
+				schema_id: 7,
+				issued_at: 1,
+				expires_at: Some(5)
+			})
+		);
+		assert!(CertificateModule::is_certificate_valid(&1, &2));
+
+		// Certificates are reported as invalid once they expire.
+		System::set_block_number(5);
+		assert!(!CertificateModule::is_certificate_valid(&1, &2));
+
+		assert_ok!(CertificateModule::revoke_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			RevocationReason::Unspecified
+		));
+		System::assert_last_event(crate::Event::CertificateRevoked(2, 1, claim_hash()).into());
+		assert_eq!(CertificateModule::certificate(1, 2), None);
+		assert!(!CertificateModule::is_certificate_valid(&1, &2));
+	});
+}
+
+#[test]
+fn test_send_certificate_with_past_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let _ = Balances::deposit_creating(&1, 100);
+		authorize(1);
+		assert_noop!(
+			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, claim(), 0, Some(5)),
+			Error::<Test>::ExpiryInPast
+		);
+	});
+}
+
+#[test]
+fn test_certificate_locks_accumulate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		authorize(1);
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			claim(),
+			0,
+			None
+		));
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			3,
+			claim(),
+			0,
+			None
+		));
+		assert_eq!(CertificateModule::certificate_deposit(1, 2), 10);
+		assert_eq!(CertificateModule::total_locked(1), 20);
+		assert_eq!(pallet_balances::Locks::<Test>::get(1)[0].amount, 20);
+
+		// Revoking one certificate only releases its own share.
+		assert_ok!(CertificateModule::revoke_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			RevocationReason::Unspecified
+		));
+		assert_eq!(CertificateModule::certificate_deposit(1, 2), 0);
+		assert_eq!(CertificateModule::total_locked(1), 10);
+		assert_eq!(pallet_balances::Locks::<Test>::get(1)[0].amount, 10);
+
+		// Revoking again does not release the remaining certificate's share.
+		assert_noop!(
+			CertificateModule::revoke_certificate(
+				RuntimeOrigin::signed(1),
+				2,
+				RevocationReason::Unspecified
+			),
+			Error::<Test>::CertificateAlreadyRevoked
+		);
+		assert_eq!(CertificateModule::total_locked(1), 10);
+
+		assert_ok!(CertificateModule::revoke_certificate(
+			RuntimeOrigin::signed(1),
+			3,
+			RevocationReason::Unspecified
+		));
+		assert_eq!(CertificateModule::total_locked(1), 0);
+		assert!(pallet_balances::Locks::<Test>::get(1).is_empty());
+	});
+}
+
+#[test]
+fn test_send_certificate_without_enough_free_balance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 15);
+		authorize(1);
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			claim(),
+			0,
+			None
+		));
+		assert_noop!(
+			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 3, claim(), 0, None),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn test_root_authorities() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CertificateModule::add_root_authority(RuntimeOrigin::signed(1), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(CertificateModule::add_root_authority(RuntimeOrigin::root(), 1));
+		System::assert_last_event(crate::Event::RootAuthorityAdded(1).into());
+		assert!(CertificateModule::is_root_authority(1));
+		assert_noop!(
+			CertificateModule::add_root_authority(RuntimeOrigin::root(), 1),
+			Error::<Test>::RootAuthorityExists
+		);
+		assert!(matches!(CertificateModule::verify_chain(&1), Ok(0)));
+
+		assert_ok!(CertificateModule::remove_root_authority(RuntimeOrigin::root(), 1));
+		System::assert_last_event(crate::Event::RootAuthorityRemoved(1).into());
+		assert!(matches!(CertificateModule::verify_chain(&1), Err(Error::<Test>::UnknownIssuer)));
+		assert_noop!(
+			CertificateModule::remove_root_authority(RuntimeOrigin::root(), 1),
+			Error::<Test>::NotRootAuthority
+		);
+	});
+}
+
+#[test]
+fn test_delegation_chain() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CertificateModule::add_root_authority(RuntimeOrigin::root(), 1));
+
+		// Only authorities with a valid chain can delegate.
+		assert_noop!(
+			CertificateModule::delegate_issuer(RuntimeOrigin::signed(2), 3),
+			Error::<Test>::UnknownIssuer
+		);
+		assert_noop!(
+			CertificateModule::delegate_issuer(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CannotDelegateSelf
+		);
+
+		assert_ok!(CertificateModule::delegate_issuer(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(crate::Event::IssuerDelegated(2, 1).into());
+		assert_ok!(CertificateModule::delegate_issuer(RuntimeOrigin::signed(2), 3));
+		assert!(matches!(CertificateModule::verify_chain(&3), Ok(2)));
+		assert_noop!(
+			CertificateModule::delegate_issuer(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::IssuerAlreadyDelegated
+		);
+		assert_noop!(
+			CertificateModule::delegate_issuer(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::IssuerAlreadyDelegated
+		);
+
+		// Revoking a link invalidates every chain passing through it.
+		assert_noop!(
+			CertificateModule::revoke_issuer(RuntimeOrigin::signed(3), 2),
+			Error::<Test>::NotDelegator
+		);
+		assert_ok!(CertificateModule::revoke_issuer(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(crate::Event::IssuerRevoked(2, 1).into());
+		assert!(matches!(CertificateModule::verify_chain(&2), Err(Error::<Test>::RevokedLink)));
+		assert!(matches!(CertificateModule::verify_chain(&3), Err(Error::<Test>::RevokedLink)));
+		assert_noop!(
+			CertificateModule::delegate_issuer(RuntimeOrigin::signed(3), 4),
+			Error::<Test>::RevokedLink
+		);
+		assert_noop!(
+			CertificateModule::revoke_issuer(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::RevokedLink
+		);
+
+		// A revoked issuer can be delegated again.
+		assert_ok!(CertificateModule::delegate_issuer(RuntimeOrigin::signed(1), 2));
+		assert!(matches!(CertificateModule::verify_chain(&3), Ok(2)));
+	});
+}
+
+#[test]
+fn test_only_authorized_issuers_send_certificates() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for issuer in 1..=3 {
+			let _ = Balances::deposit_creating(&issuer, 100);
+		}
+
+		// An account outside every delegation chain cannot issue certificates.
+		assert_noop!(
+			CertificateModule::send_certificate(RuntimeOrigin::signed(3), 4, claim(), 0, None),
+			Error::<Test>::UnknownIssuer
+		);
+
+		authorize(1);
+		assert_ok!(CertificateModule::delegate_issuer(RuntimeOrigin::signed(1), 2));
+		assert_ok!(CertificateModule::delegate_issuer(RuntimeOrigin::signed(2), 3));
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(3),
+			4,
+			claim(),
+			0,
+			None
+		));
+
+		// Revoking an intermediate link stops every issuer below it from sending certificates.
+		assert_ok!(CertificateModule::revoke_issuer(RuntimeOrigin::signed(1), 2));
+		assert_noop!(
+			CertificateModule::send_certificate(RuntimeOrigin::signed(2), 4, claim(), 0, None),
+			Error::<Test>::RevokedLink
+		);
+		assert_noop!(
+			CertificateModule::send_certificate(RuntimeOrigin::signed(3), 5, claim(), 0, None),
+			Error::<Test>::RevokedLink
+		);
+		assert!(CertificateModule::is_certificate_valid(&3, &4));
+	});
+}
+
+#[test]
+fn test_delegation_chain_depth() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CertificateModule::add_root_authority(RuntimeOrigin::root(), 1));
+		for issuer in 2..=6 {
+			assert_ok!(CertificateModule::delegate_issuer(
+				RuntimeOrigin::signed(issuer - 1),
+				issuer
+			));
+		}
+		assert!(matches!(CertificateModule::verify_chain(&6), Ok(5)));
+		assert_noop!(
+			CertificateModule::delegate_issuer(RuntimeOrigin::signed(6), 7),
+			Error::<Test>::ChainTooLong
+		);
+	});
+}
+
+#[test]
+fn test_revocation_list() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		authorize(1);
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			claim(),
+			0,
+			None
+		));
+		System::set_block_number(3);
+		assert_ok!(CertificateModule::revoke_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			RevocationReason::Superseded
+		));
+		assert_eq!(
+			CertificateModule::revocation(1, 2),
+			Some(Revocation { reason: RevocationReason::Superseded, revoked_at: 3 })
+		);
+		assert_eq!(CertificateModule::revocation(1, 3), None);
+	});
+}
+
+#[test]
+fn test_reject_certificate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		authorize(1);
+		assert_noop!(
+			CertificateModule::reject_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::CertificateNotFound
+		);
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			claim(),
+			0,
+			None
+		));
+
+		assert_eq!(CertificateModule::certificates_issued_to(&2).len(), 1);
+
+		assert_ok!(CertificateModule::reject_certificate(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(crate::Event::CertificateRejected(2, 1, claim_hash()).into());
+		assert!(!CertificateModule::is_certificate_valid(&1, &2));
+		// The recipient no longer sees the certificate it rejected, but the issuer still does.
+		assert!(CertificateModule::certificates_issued_to(&2).is_empty());
+		assert_eq!(CertificateModule::certificates_issued_by(&1).len(), 1);
+		assert_eq!(CertificateModule::total_locked(1), 0);
+		assert_eq!(
+			CertificateModule::revocation(1, 2),
+			Some(Revocation { reason: RevocationReason::RejectedByRecipient, revoked_at: 1 })
+		);
+
+		// The issuer can neither revoke the rejected certificate nor send a new one.
+		assert_noop!(
+			CertificateModule::reject_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::CertificateAlreadyRevoked
+		);
+		assert_noop!(
+			CertificateModule::revoke_certificate(
+				RuntimeOrigin::signed(1),
+				2,
+				RevocationReason::Unspecified
+			),
+			Error::<Test>::CertificateAlreadyRevoked
+		);
+		assert_noop!(
+			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 2, claim(), 0, None),
+			Error::<Test>::CertificateExists
+		);
+	});
+}
+
+#[test]
+fn test_certificate_queries() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		authorize(1);
+		let _ = Balances::deposit_creating(&2, 100);
+		authorize(2);
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(1),
+			3,
+			claim(),
+			0,
+			Some(5)
+		));
+		assert_ok!(CertificateModule::send_certificate(
+			RuntimeOrigin::signed(2),
+			3,
+			claim(),
+			0,
+			None
+		));
+		assert_ok!(CertificateModule::revoke_certificate(
+			RuntimeOrigin::signed(2),
+			3,
+			RevocationReason::ClaimInvalid
+		));
+		System::set_block_number(5);
+
+		let expired = CertificateInfo {
+			issuer: 1,
+			recipient: 3,
+			details: Some(CertificateDetails {
+				claim: claim().into_inner(),
+				schema_id: 0,
+				issued_at: 1,
+				expires_at: Some(5),
+			}),
+			status: CertificateStatus::Expired,
+		};
+		let revoked = CertificateInfo {
+			issuer: 2,
+			recipient: 3,
+			details: None,
+			status: CertificateStatus::Revoked(Some(Revocation {
+				reason: RevocationReason::ClaimInvalid,
+				revoked_at: 1,
+			})),
+		};
+		assert_eq!(CertificateModule::certificates_issued_by(&1), vec![expired.clone()]);
+		assert_eq!(CertificateModule::certificates_issued_by(&2), vec![revoked.clone()]);
+
+		let mut received = CertificateModule::certificates_issued_to(&3);
+		received.sort_by_key(|info| info.issuer);
+		assert_eq!(received, vec![expired, revoked]);
+		assert!(CertificateModule::certificates_issued_to(&1).is_empty());
+	});
+}
+
+#[test]
+fn migrate_recipient_index() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<CertificateModule>();
+		// Certificates sent before the recipient index existed are only in `CertificateList`,
+		// and revoked ones are kept there as `false`.
+		CertificateList::<Test>::insert(1, 2, true);
+		CertificateList::<Test>::insert(3, 2, false);
+
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(CertificateModule::on_chain_storage_version(), 1);
+		assert!(CertificatesByRecipient::<Test>::contains_key(2, 1));
+		assert!(CertificatesByRecipient::<Test>::contains_key(2, 3));
+		assert_eq!(CertificateModule::certificates_issued_to(&2).len(), 2);
+	});
+}
+
+#[test]
+fn migrate_certificate_deposits() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<CertificateModule>();
+		let _ = Balances::deposit_creating(&1, 100);
+		let _ = Balances::deposit_creating(&4, 100);
+		// Before deposits were tracked, every certificate set the issuer's lock to a fixed 10
+		// and any revocation removed it.
+		CertificateList::<Test>::insert(1, 2, true);
+		CertificateList::<Test>::insert(1, 3, true);
+		Balances::set_lock(MockLockIdentifier::get(), &1, 10, WithdrawReasons::all());
+		CertificateList::<Test>::insert(4, 2, false);
+
+		migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(CertificateModule::on_chain_storage_version(), 2);
+		assert_eq!(CertificateModule::certificate_deposit(1, 2), 10);
+		assert_eq!(CertificateModule::certificate_deposit(1, 3), 10);
+		assert_eq!(CertificateModule::total_locked(1), 20);
+		assert_eq!(pallet_balances::Locks::<Test>::get(1)[0].amount, 20);
+		assert_eq!(CertificateModule::certificate_deposit(4, 2), 0);
+		assert_eq!(CertificateModule::total_locked(4), 0);
+		assert!(pallet_balances::Locks::<Test>::get(4).is_empty());
+
+		// Revoking a migrated certificate releases only its share.
+		assert_ok!(CertificateModule::revoke_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			RevocationReason::Unspecified
+		));
+		assert_eq!(CertificateModule::total_locked(1), 10);
+		assert_eq!(pallet_balances::Locks::<Test>::get(1)[0].amount, 10);
+	});
+}
@@ -32,6 +32,11 @@ pub trait WeightInfo {
 	fn send_certificate_heavy_storage() -> Weight;
 	fn revoke_certificate() -> Weight;
 	fn revoke_certificate_heavy_storage() -> Weight;
+	fn add_root_authority() -> Weight;
+	fn remove_root_authority() -> Weight;
+	fn delegate_issuer(n: u32) -> Weight;
+	fn revoke_issuer() -> Weight;
+	fn reject_certificate() -> Weight;
 }
 
 /// Weights for pallet_certificate using the Substrate node and recommended hardware.
@@ -40,35 +45,157 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
 	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added:
 	/// 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Certificates` (r:0 w:1)
+	/// Proof: `Certificate::Certificates` (`max_values`: None, `max_size`: Some(1138), added:
+	/// 3613, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateDeposits` (r:0 w:1)
+	/// Proof: `Certificate::CertificateDeposits` (`max_values`: None, `max_size`: Some(112),
+	/// added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::TotalLocked` (r:1 w:1)
+	/// Proof: `Certificate::TotalLocked` (`max_values`: None, `max_size`: Some(64), added: 2539,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesByRecipient` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesByRecipient` (`max_values`: None, `max_size`: Some(96),
+	/// added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::RootAuthorities` (r:6 w:0)
+	/// Proof: `Certificate::RootAuthorities` (`max_values`: None, `max_size`: Some(49), added:
+	/// 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Delegations` (r:5 w:0)
+	/// Proof: `Certificate::Delegations` (`max_values`: None, `max_size`: Some(81), added: 2556,
+	/// mode: `MaxEncodedLen`)
 	fn send_certificate() -> Weight {
-		Weight::from_parts(13_000_000, 3562)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(13_000_000, 31486)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
 	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added:
 	/// 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Certificates` (r:0 w:1)
+	/// Proof: `Certificate::Certificates` (`max_values`: None, `max_size`: Some(1138), added:
+	/// 3613, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateDeposits` (r:0 w:1)
+	/// Proof: `Certificate::CertificateDeposits` (`max_values`: None, `max_size`: Some(112),
+	/// added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::TotalLocked` (r:1 w:1)
+	/// Proof: `Certificate::TotalLocked` (`max_values`: None, `max_size`: Some(64), added: 2539,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesByRecipient` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesByRecipient` (`max_values`: None, `max_size`: Some(96),
+	/// added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::RootAuthorities` (r:6 w:0)
+	/// Proof: `Certificate::RootAuthorities` (`max_values`: None, `max_size`: Some(49), added:
+	/// 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Delegations` (r:5 w:0)
+	/// Proof: `Certificate::Delegations` (`max_values`: None, `max_size`: Some(81), added: 2556,
+	/// mode: `MaxEncodedLen`)
 	fn send_certificate_heavy_storage() -> Weight {
-		Weight::from_parts(38_000_000, 3562)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(38_000_000, 31486)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
 	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added:
 	/// 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Certificates` (r:1 w:1)
+	/// Proof: `Certificate::Certificates` (`max_values`: None, `max_size`: Some(1138), added:
+	/// 3613, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateDeposits` (r:1 w:1)
+	/// Proof: `Certificate::CertificateDeposits` (`max_values`: None, `max_size`: Some(112),
+	/// added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::TotalLocked` (r:1 w:1)
+	/// Proof: `Certificate::TotalLocked` (`max_values`: None, `max_size`: Some(64), added: 2539,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Revocations` (r:0 w:1)
+	/// Proof: `Certificate::Revocations` (`max_values`: None, `max_size`: Some(101), added: 2576,
+	/// mode: `MaxEncodedLen`)
 	fn revoke_certificate() -> Weight {
-		Weight::from_parts(15_000_000, 3562)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(15_000_000, 4603)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
 	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added:
 	/// 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Certificates` (r:1 w:1)
+	/// Proof: `Certificate::Certificates` (`max_values`: None, `max_size`: Some(1138), added:
+	/// 3613, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateDeposits` (r:1 w:1)
+	/// Proof: `Certificate::CertificateDeposits` (`max_values`: None, `max_size`: Some(112),
+	/// added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::TotalLocked` (r:1 w:1)
+	/// Proof: `Certificate::TotalLocked` (`max_values`: None, `max_size`: Some(64), added: 2539,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Revocations` (r:0 w:1)
+	/// Proof: `Certificate::Revocations` (`max_values`: None, `max_size`: Some(101), added: 2576,
+	/// mode: `MaxEncodedLen`)
 	fn revoke_certificate_heavy_storage() -> Weight {
-		Weight::from_parts(39_000_000, 3562)
+		Weight::from_parts(39_000_000, 4603)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Certificate::RootAuthorities` (r:1 w:1)
+	/// Proof: `Certificate::RootAuthorities` (`max_values`: None, `max_size`: Some(49), added:
+	/// 2524, mode: `MaxEncodedLen`)
+	fn add_root_authority() -> Weight {
+		Weight::from_parts(11_000_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Certificate::RootAuthorities` (r:1 w:1)
+	/// Proof: `Certificate::RootAuthorities` (`max_values`: None, `max_size`: Some(49), added:
+	/// 2524, mode: `MaxEncodedLen`)
+	fn remove_root_authority() -> Weight {
+		Weight::from_parts(12_000_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Certificate::RootAuthorities` (r:6 w:0)
+	/// Proof: `Certificate::RootAuthorities` (`max_values`: None, `max_size`: Some(49), added:
+	/// 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Delegations` (r:6 w:1)
+	/// Proof: `Certificate::Delegations` (`max_values`: None, `max_size`: Some(81), added: 2556,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 4]`.
+	fn delegate_issuer(n: u32) -> Weight {
+		Weight::from_parts(17_000_000, 3546)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2556).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Certificate::Delegations` (r:1 w:1)
+	/// Proof: `Certificate::Delegations` (`max_values`: None, `max_size`: Some(81), added: 2556,
+	/// mode: `MaxEncodedLen`)
+	fn revoke_issuer() -> Weight {
+		Weight::from_parts(13_000_000, 3546)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Certificate::CertificateList` (r:1 w:1)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added:
+	/// 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateDeposits` (r:1 w:1)
+	/// Proof: `Certificate::CertificateDeposits` (`max_values`: None, `max_size`: Some(112),
+	/// added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::TotalLocked` (r:1 w:1)
+	/// Proof: `Certificate::TotalLocked` (`max_values`: None, `max_size`: Some(64), added: 2539,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Certificates` (r:1 w:1)
+	/// Proof: `Certificate::Certificates` (`max_values`: None, `max_size`: Some(1138), added:
+	/// 3613, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Revocations` (r:0 w:1)
+	/// Proof: `Certificate::Revocations` (`max_values`: None, `max_size`: Some(101), added: 2576,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesByRecipient` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesByRecipient` (`max_values`: None, `max_size`: Some(96),
+	/// added: 2571, mode: `MaxEncodedLen`)
+	fn reject_certificate() -> Weight {
+		Weight::from_parts(16_000_000, 4603)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -76,49 +203,191 @@ impl WeightInfo for () {
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
 	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added:
 	/// 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Certificates` (r:0 w:1)
+	/// Proof: `Certificate::Certificates` (`max_values`: None, `max_size`: Some(1138), added:
+	/// 3613, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateDeposits` (r:0 w:1)
+	/// Proof: `Certificate::CertificateDeposits` (`max_values`: None, `max_size`: Some(112),
+	/// added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::TotalLocked` (r:1 w:1)
+	/// Proof: `Certificate::TotalLocked` (`max_values`: None, `max_size`: Some(64), added: 2539,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesByRecipient` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesByRecipient` (`max_values`: None, `max_size`: Some(96),
+	/// added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::RootAuthorities` (r:6 w:0)
+	/// Proof: `Certificate::RootAuthorities` (`max_values`: None, `max_size`: Some(49), added:
+	/// 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Delegations` (r:5 w:0)
+	/// Proof: `Certificate::Delegations` (`max_values`: None, `max_size`: Some(81), added: 2556,
+	/// mode: `MaxEncodedLen`)
 	fn send_certificate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `80`
-		//  Estimated: `3562`
+		//  Estimated: `31486`
 		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(13_000_000, 3562)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(13_000_000, 31486)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
 	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added:
 	/// 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Certificates` (r:0 w:1)
+	/// Proof: `Certificate::Certificates` (`max_values`: None, `max_size`: Some(1138), added:
+	/// 3613, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateDeposits` (r:0 w:1)
+	/// Proof: `Certificate::CertificateDeposits` (`max_values`: None, `max_size`: Some(112),
+	/// added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::TotalLocked` (r:1 w:1)
+	/// Proof: `Certificate::TotalLocked` (`max_values`: None, `max_size`: Some(64), added: 2539,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesByRecipient` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesByRecipient` (`max_values`: None, `max_size`: Some(96),
+	/// added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::RootAuthorities` (r:6 w:0)
+	/// Proof: `Certificate::RootAuthorities` (`max_values`: None, `max_size`: Some(49), added:
+	/// 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Delegations` (r:5 w:0)
+	/// Proof: `Certificate::Delegations` (`max_values`: None, `max_size`: Some(81), added: 2556,
+	/// mode: `MaxEncodedLen`)
 	fn send_certificate_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1725`
-		//  Estimated: `3562`
+		//  Estimated: `31486`
 		// Minimum execution time: 33_000_000 picoseconds.
-		Weight::from_parts(38_000_000, 3562)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(38_000_000, 31486)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
 	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added:
 	/// 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Certificates` (r:1 w:1)
+	/// Proof: `Certificate::Certificates` (`max_values`: None, `max_size`: Some(1138), added:
+	/// 3613, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateDeposits` (r:1 w:1)
+	/// Proof: `Certificate::CertificateDeposits` (`max_values`: None, `max_size`: Some(112),
+	/// added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::TotalLocked` (r:1 w:1)
+	/// Proof: `Certificate::TotalLocked` (`max_values`: None, `max_size`: Some(64), added: 2539,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Revocations` (r:0 w:1)
+	/// Proof: `Certificate::Revocations` (`max_values`: None, `max_size`: Some(101), added: 2576,
+	/// mode: `MaxEncodedLen`)
 	fn revoke_certificate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `203`
-		//  Estimated: `3562`
+		//  Estimated: `4603`
 		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(15_000_000, 3562)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(15_000_000, 4603)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
 	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added:
 	/// 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Certificates` (r:1 w:1)
+	/// Proof: `Certificate::Certificates` (`max_values`: None, `max_size`: Some(1138), added:
+	/// 3613, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateDeposits` (r:1 w:1)
+	/// Proof: `Certificate::CertificateDeposits` (`max_values`: None, `max_size`: Some(112),
+	/// added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::TotalLocked` (r:1 w:1)
+	/// Proof: `Certificate::TotalLocked` (`max_values`: None, `max_size`: Some(64), added: 2539,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Revocations` (r:0 w:1)
+	/// Proof: `Certificate::Revocations` (`max_values`: None, `max_size`: Some(101), added: 2576,
+	/// mode: `MaxEncodedLen`)
 	fn revoke_certificate_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1742`
-		//  Estimated: `3562`
+		//  Estimated: `4603`
 		// Minimum execution time: 35_000_000 picoseconds.
-		Weight::from_parts(39_000_000, 3562)
+		Weight::from_parts(39_000_000, 4603)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Certificate::RootAuthorities` (r:1 w:1)
+	/// Proof: `Certificate::RootAuthorities` (`max_values`: None, `max_size`: Some(49), added:
+	/// 2524, mode: `MaxEncodedLen`)
+	fn add_root_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3514`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Certificate::RootAuthorities` (r:1 w:1)
+	/// Proof: `Certificate::RootAuthorities` (`max_values`: None, `max_size`: Some(49), added:
+	/// 2524, mode: `MaxEncodedLen`)
+	fn remove_root_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `87`
+		//  Estimated: `3514`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Certificate::RootAuthorities` (r:6 w:0)
+	/// Proof: `Certificate::RootAuthorities` (`max_values`: None, `max_size`: Some(49), added:
+	/// 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Delegations` (r:6 w:1)
+	/// Proof: `Certificate::Delegations` (`max_values`: None, `max_size`: Some(81), added: 2556,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 4]`.
+	fn delegate_issuer(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `87`
+		//  Estimated: `3546`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3546)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2556).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Certificate::Delegations` (r:1 w:1)
+	/// Proof: `Certificate::Delegations` (`max_values`: None, `max_size`: Some(81), added: 2556,
+	/// mode: `MaxEncodedLen`)
+	fn revoke_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `150`
+		//  Estimated: `3546`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3546)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Certificate::CertificateList` (r:1 w:1)
+	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added:
+	/// 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificateDeposits` (r:1 w:1)
+	/// Proof: `Certificate::CertificateDeposits` (`max_values`: None, `max_size`: Some(112),
+	/// added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::TotalLocked` (r:1 w:1)
+	/// Proof: `Certificate::TotalLocked` (`max_values`: None, `max_size`: Some(64), added: 2539,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Certificates` (r:1 w:1)
+	/// Proof: `Certificate::Certificates` (`max_values`: None, `max_size`: Some(1138), added:
+	/// 3613, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::Revocations` (r:0 w:1)
+	/// Proof: `Certificate::Revocations` (`max_values`: None, `max_size`: Some(101), added: 2576,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::CertificatesByRecipient` (r:0 w:1)
+	/// Proof: `Certificate::CertificatesByRecipient` (`max_values`: None, `max_size`: Some(96),
+	/// added: 2571, mode: `MaxEncodedLen`)
+	fn reject_certificate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1286`
+		//  Estimated: `4603`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 4603)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
@@ -0,0 +1,26 @@
+//! Runtime API definition for the identity pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// Lookups into the identities created through the identity pallet.
+	pub trait IdentityApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// The ID numbers of every identity `account` owns.
+		fn identities_of(account: AccountId) -> Vec<u32>;
+		/// The accounts that have vouched for the current value of the trait `key` on the
+		/// identity `identity_id`.
+		fn trait_attesters(identity_id: u32, key: Vec<u8>) -> Vec<AccountId>;
+		/// Whether `value` and `salt` open the commitment stored for the private trait `key` on
+		/// the identity `identity_id`. Lets a verifier check a disclosed trait without publishing
+		/// it on-chain.
+		fn verify_trait_commitment(identity_id: u32, key: Vec<u8>, value: Vec<u8>, salt: Vec<u8>) -> bool;
+	}
+}
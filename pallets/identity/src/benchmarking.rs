@@ -3,9 +3,13 @@ use super::*;
 use crate::Pallet as Identity;
 
 use frame_benchmarking::{account as benchmark_account, v2::*};
-use frame_support::BoundedVec;
+use frame_support::{
+	traits::{Currency, Get},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
-use scale_info::prelude::{format, vec};
+use scale_info::prelude::{format, vec, vec::Vec};
+use sp_runtime::traits::{Bounded, Hash};
 
 pub fn get_account<T: Config>(name: &'static str) -> T::AccountId {
 	let account: T::AccountId = benchmark_account(name, 0, 0);
@@ -16,6 +20,52 @@ pub fn get_origin<T: Config>(name: &'static str) -> RawOrigin<T::AccountId> {
 	RawOrigin::Signed(get_account::<T>(name))
 }
 
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Gives `name` enough funds to cover the deposits of every trait a benchmark adds.
+pub fn fund_account<T: Config>(name: &'static str) {
+	T::Currency::make_free_balance_be(
+		&get_account::<T>(name),
+		BalanceOf::<T>::max_value() / 2u32.into(),
+	);
+}
+
+/// Creates `count` identities, each owned by a different account.
+pub fn create_identities_for_others<T: Config>(count: u32) -> Result<(), BenchmarkError> {
+	for i in 0..count {
+		let owner: T::AccountId = benchmark_account("owner", i, 0);
+		Identity::<T>::create_identity(RawOrigin::Signed(owner).into())?;
+	}
+	Ok(())
+}
+
+/// The largest set of recovery accounts an identity can name, sorted.
+pub fn recovery_friends<T: Config>() -> FriendsOf<T> {
+	let mut friends: Vec<T::AccountId> = (0..T::MaxRecoveryAccounts::get())
+		.map(|i| benchmark_account("friend", i, 0))
+		.collect();
+	friends.sort();
+	friends.try_into().unwrap()
+}
+
+/// Sets up recovery for `identity_index`, owned by `owner`, requiring every recovery account
+/// and a one block delay.
+pub fn configure_recovery<T: Config>(
+	owner: RawOrigin<T::AccountId>,
+	identity_index: u32,
+) -> Result<FriendsOf<T>, BenchmarkError> {
+	let friends = recovery_friends::<T>();
+	Identity::<T>::set_recovery_config(
+		owner.into(),
+		identity_index,
+		friends.clone(),
+		friends.len() as u16,
+		1u32.into(),
+	)?;
+	Ok(friends)
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -24,7 +74,9 @@ mod benchmarks {
 	fn create_identity() -> Result<(), BenchmarkError> {
 		let anakin = get_origin::<T>("Anakin");
 
-		for _ in 0..1000 {
+		create_identities_for_others::<T>(1000)?;
+		// Leave room for exactly one more identity in the caller's own list.
+		for _ in 1..T::MaxIdentitiesPerOwner::get() {
 			Identity::<T>::create_identity(anakin.clone().into())?;
 		}
 
@@ -57,21 +109,24 @@ mod benchmarks {
 	fn revoke_identity_heavy_storage() -> Result<(), BenchmarkError> {
 		let anakin = get_origin::<T>("Anakin");
 
-		for _ in 0..1000 {
+		create_identities_for_others::<T>(1000)?;
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		for _ in 0..T::MaxIdentitiesPerOwner::get() {
 			Identity::<T>::create_identity(anakin.clone().into())?;
 		}
 
 		#[extrinsic_call]
-		revoke_identity(anakin.clone(), 301);
+		revoke_identity(anakin.clone(), identity_index);
 
 		// Check that the identity no longer exists in the IdentityList.
-		assert!(!IdentityList::<T>::contains_key(301));
+		assert!(!IdentityList::<T>::contains_key(identity_index));
 
 		Ok(())
 	}
 
 	#[benchmark]
 	fn add_or_update_identity_trait() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let anakin = get_origin::<T>("Anakin");
 		let name: BoundedVec<u8, T::MaxSize> = "name".as_bytes().to_vec().try_into().unwrap();
 		let value: BoundedVec<u8, T::MaxSize> = "Skywalker".as_bytes().to_vec().try_into().unwrap();
@@ -89,6 +144,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn add_or_update_long_identity_trait() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let anakin = get_origin::<T>("Anakin");
 		let name: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
 		let value: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
@@ -111,6 +167,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn add_or_update_many_identity_traits() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let anakin = get_origin::<T>("Anakin");
 		let identity_index: u32 = IdentityNumber::<T>::get();
 		Identity::<T>::create_identity(anakin.clone().into())?;
@@ -129,6 +186,7 @@ mod benchmarks {
 			)?;
 		}
 
+		fund_account::<T>("Obi-Wan");
 		let obiwan = get_origin::<T>("Obi-Wan");
 		let new_identity_index: u32 = IdentityNumber::<T>::get();
 		Identity::<T>::create_identity(obiwan.clone().into())?;
@@ -145,6 +203,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn remove_identity_trait() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let anakin = get_origin::<T>("Anakin");
 		let name: BoundedVec<u8, T::MaxSize> = "name".as_bytes().to_vec().try_into().unwrap();
 		let value: BoundedVec<u8, T::MaxSize> = "Skywalker".as_bytes().to_vec().try_into().unwrap();
@@ -168,6 +227,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn remove_identity_trait_heavy_storage() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let anakin = get_origin::<T>("Anakin");
 		let identity_index: u32 = IdentityNumber::<T>::get();
 		Identity::<T>::create_identity(anakin.clone().into())?;
@@ -198,6 +258,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn remove_long_identity_trait() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let anakin = get_origin::<T>("Anakin");
 		let name: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
 		let value: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
@@ -219,5 +280,417 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn offer_identity_transfer() -> Result<(), BenchmarkError> {
+		let anakin = get_origin::<T>("Anakin");
+		let luke = get_account::<T>("Luke");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+
+		#[extrinsic_call]
+		_(anakin, identity_index, luke.clone());
+
+		assert_eq!(PendingTransfers::<T>::get(identity_index), Some(luke));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_identity_transfer() -> Result<(), BenchmarkError> {
+		let anakin = get_origin::<T>("Anakin");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+		Identity::<T>::offer_identity_transfer(
+			anakin.clone().into(),
+			identity_index,
+			get_account::<T>("Luke"),
+		)?;
+
+		#[extrinsic_call]
+		_(anakin, identity_index);
+
+		assert!(!PendingTransfers::<T>::contains_key(identity_index));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_identity_transfer(n: Linear<0, 1000>) -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		fund_account::<T>("Luke");
+		let anakin = get_origin::<T>("Anakin");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+
+		for i in 0..n {
+			let name: BoundedVec<u8, T::MaxSize> =
+				format!("name{}", i).as_bytes().to_vec().try_into().unwrap();
+			let value: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+
+			Identity::<T>::add_or_update_identity_trait(
+				anakin.clone().into(),
+				identity_index,
+				name,
+				value,
+			)?;
+		}
+		Identity::<T>::offer_identity_transfer(
+			anakin.into(),
+			identity_index,
+			get_account::<T>("Luke"),
+		)?;
+
+		#[extrinsic_call]
+		_(get_origin::<T>("Luke"), identity_index, n);
+
+		assert_eq!(IdentityList::<T>::get(identity_index), Some(get_account::<T>("Luke")));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn attest_identity_trait() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		fund_account::<T>("Obi-Wan");
+		let anakin = get_origin::<T>("Anakin");
+		let name: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+		let value: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+		let value_hash = T::Hashing::hash(&value);
+
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+		Identity::<T>::add_or_update_identity_trait(
+			anakin.into(),
+			identity_index,
+			name.clone(),
+			value,
+		)?;
+
+		#[extrinsic_call]
+		_(get_origin::<T>("Obi-Wan"), identity_index, name.clone(), value_hash);
+
+		assert!(Identity::<T>::is_trait_attested_by(
+			identity_index,
+			&name,
+			&get_account::<T>("Obi-Wan")
+		));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_attestation() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		fund_account::<T>("Obi-Wan");
+		let anakin = get_origin::<T>("Anakin");
+		let obiwan = get_origin::<T>("Obi-Wan");
+		let name: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+		let value: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+		let value_hash = T::Hashing::hash(&value);
+
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+		Identity::<T>::add_or_update_identity_trait(
+			anakin.into(),
+			identity_index,
+			name.clone(),
+			value,
+		)?;
+		Identity::<T>::attest_identity_trait(
+			obiwan.clone().into(),
+			identity_index,
+			name.clone(),
+			value_hash,
+		)?;
+
+		#[extrinsic_call]
+		_(obiwan, identity_index, name.clone());
+
+		assert!(!TraitAttestations::<T>::contains_key((
+			identity_index,
+			name,
+			get_account::<T>("Obi-Wan")
+		)));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_identity_traits(n: Linear<1, 64>) -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		let anakin = get_origin::<T>("Anakin");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+
+		let operations: Vec<TraitOperationOf<T>> = (0..n)
+			.map(|i| {
+				let mut name = vec![0; 1000];
+				name[..4].copy_from_slice(&i.to_le_bytes());
+				TraitOperation::Set(name.try_into().unwrap(), vec![0; 1000].try_into().unwrap())
+			})
+			.collect();
+		let operations: BoundedVec<TraitOperationOf<T>, T::MaxTraitOperations> =
+			operations.try_into().unwrap();
+
+		#[extrinsic_call]
+		_(anakin, identity_index, operations);
+
+		assert_eq!(IdentityTraitList::<T>::iter_prefix(identity_index).count() as u32, n);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_identity_traits(n: Linear<1, 1000>) -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		let anakin = get_origin::<T>("Anakin");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+
+		for i in 0..n {
+			let name: BoundedVec<u8, T::MaxSize> =
+				format!("name{}", i).as_bytes().to_vec().try_into().unwrap();
+			let value: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+
+			Identity::<T>::add_or_update_identity_trait(
+				anakin.clone().into(),
+				identity_index,
+				name,
+				value,
+			)?;
+		}
+		// Revoke the identity without clearing any of its traits.
+		IdentityList::<T>::remove(identity_index);
+
+		#[extrinsic_call]
+		_(get_origin::<T>("Obi-Wan"), identity_index, n);
+
+		assert_eq!(IdentityTraitList::<T>::iter_prefix(identity_index).count(), 0);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn commit_identity_trait() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		let anakin = get_origin::<T>("Anakin");
+		let name: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+		let value: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+		let commitment = Identity::<T>::commitment_of(&value, b"salt");
+
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+		// Start from a plaintext trait so the commitment replaces it.
+		Identity::<T>::add_or_update_identity_trait(
+			anakin.clone().into(),
+			identity_index,
+			name.clone(),
+			value,
+		)?;
+
+		#[extrinsic_call]
+		_(anakin, identity_index, name.clone(), commitment);
+
+		assert_eq!(TraitCommitments::<T>::get(identity_index, &name), Some(commitment));
+		assert!(!IdentityTraitList::<T>::contains_key(identity_index, name));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reveal_identity_trait() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		let anakin = get_origin::<T>("Anakin");
+		let name: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+		let value: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+		let salt: BoundedVec<u8, T::MaxSize> = vec![1; 1000].try_into().unwrap();
+		let commitment = Identity::<T>::commitment_of(&value, &salt);
+
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+		Identity::<T>::commit_identity_trait(
+			anakin.into(),
+			identity_index,
+			name.clone(),
+			commitment,
+		)?;
+
+		#[extrinsic_call]
+		_(get_origin::<T>("Obi-Wan"), identity_index, name.clone(), value.clone(), salt.clone());
+
+		assert!(Identity::<T>::verify_trait_commitment(identity_index, &name, &value, &salt));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_trait_schema() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PrivilegedOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let name: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+		let schema = TraitSchema { format: TraitFormat::Email, max_length: 1000 };
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name.clone(), schema.clone());
+
+		assert_eq!(TraitSchemas::<T>::get(name), Some(schema));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_trait_schema() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PrivilegedOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let name: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+		TraitSchemas::<T>::insert(&name, TraitSchema { format: TraitFormat::Utf8, max_length: 64 });
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name.clone());
+
+		assert!(!TraitSchemas::<T>::contains_key(name));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_recovery_config() -> Result<(), BenchmarkError> {
+		let anakin = get_origin::<T>("Anakin");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+		let friends = recovery_friends::<T>();
+
+		#[extrinsic_call]
+		_(anakin, identity_index, friends.clone(), friends.len() as u16, 10u32.into());
+
+		assert!(RecoveryConfigs::<T>::contains_key(identity_index));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_recovery_config() -> Result<(), BenchmarkError> {
+		let anakin = get_origin::<T>("Anakin");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+		configure_recovery::<T>(anakin.clone(), identity_index)?;
+
+		#[extrinsic_call]
+		_(anakin, identity_index);
+
+		assert!(!RecoveryConfigs::<T>::contains_key(identity_index));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn initiate_recovery() -> Result<(), BenchmarkError> {
+		let anakin = get_origin::<T>("Anakin");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+		configure_recovery::<T>(anakin, identity_index)?;
+		fund_account::<T>("Luke");
+
+		#[extrinsic_call]
+		_(get_origin::<T>("Luke"), identity_index);
+
+		assert!(ActiveRecoveries::<T>::contains_key(identity_index));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vouch_recovery() -> Result<(), BenchmarkError> {
+		let anakin = get_origin::<T>("Anakin");
+		let luke = get_account::<T>("Luke");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+		let mut friends = configure_recovery::<T>(anakin, identity_index)?.into_inner();
+		fund_account::<T>("Luke");
+		Identity::<T>::initiate_recovery(get_origin::<T>("Luke").into(), identity_index)?;
+
+		// Every other recovery account vouches first, so the last vouch is the most expensive.
+		let last = friends.pop().unwrap();
+		for friend in friends {
+			Identity::<T>::vouch_recovery(
+				RawOrigin::Signed(friend).into(),
+				identity_index,
+				luke.clone(),
+			)?;
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(last), identity_index, luke);
+
+		assert_eq!(
+			ActiveRecoveries::<T>::get(identity_index).unwrap().vouchers.len() as u32,
+			T::MaxRecoveryAccounts::get()
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn finalize_recovery(n: Linear<0, 1000>) -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		fund_account::<T>("Luke");
+		let anakin = get_origin::<T>("Anakin");
+		let luke = get_account::<T>("Luke");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+
+		for i in 0..n {
+			let name: BoundedVec<u8, T::MaxSize> =
+				format!("name{}", i).as_bytes().to_vec().try_into().unwrap();
+			let value: BoundedVec<u8, T::MaxSize> = vec![0; 1000].try_into().unwrap();
+
+			Identity::<T>::add_or_update_identity_trait(
+				anakin.clone().into(),
+				identity_index,
+				name,
+				value,
+			)?;
+		}
+		let friends = configure_recovery::<T>(anakin, identity_index)?;
+		Identity::<T>::initiate_recovery(get_origin::<T>("Luke").into(), identity_index)?;
+		for friend in friends {
+			Identity::<T>::vouch_recovery(
+				RawOrigin::Signed(friend).into(),
+				identity_index,
+				luke.clone(),
+			)?;
+		}
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + 1u32.into(),
+		);
+
+		#[extrinsic_call]
+		_(get_origin::<T>("Luke"), identity_index, n);
+
+		assert_eq!(IdentityList::<T>::get(identity_index), Some(luke));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_recovery() -> Result<(), BenchmarkError> {
+		let anakin = get_origin::<T>("Anakin");
+		let identity_index: u32 = IdentityNumber::<T>::get();
+		Identity::<T>::create_identity(anakin.clone().into())?;
+		let friends = configure_recovery::<T>(anakin, identity_index)?;
+		fund_account::<T>("Luke");
+		Identity::<T>::initiate_recovery(get_origin::<T>("Luke").into(), identity_index)?;
+
+		// A recovery account cancelling has to look through the recovery config as well.
+		let last = friends.last().unwrap().clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(last), identity_index);
+
+		assert!(!ActiveRecoveries::<T>::contains_key(identity_index));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Identity, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 #[cfg(test)]
 mod mock;
 
@@ -9,6 +11,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
 pub use pallet::*;
@@ -17,9 +20,17 @@ use weights::*;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+	use alloc::vec::Vec;
+	use frame_support::{
+		dispatch::DispatchResult,
+		pallet_prelude::*,
+		traits::{Currency, ReservableCurrency},
+	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::One;
+	use sp_runtime::traits::{Hash, One, Saturating, Zero};
+
+	type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -29,9 +40,154 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		// Please add one line of comment here about this config
 		type MaxSize: Get<u32>;
+		/// The currency in which storage deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The deposit reserved for each byte of key and value an identity trait stores.
+		type DepositPerByte: Get<BalanceOf<Self>>;
+		/// The maximum number of identities a single account can own.
+		type MaxIdentitiesPerOwner: Get<u32>;
+		/// The maximum number of trait operations in a single `set_identity_traits` call, and
+		/// the number of traits `revoke_identity` clears before leaving the rest to
+		/// `clear_identity_traits`.
+		type MaxTraitOperations: Get<u32>;
+		/// Privileged origin that can register and remove trait schemas.
+		type PrivilegedOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The maximum number of recovery accounts an identity can name.
+		type MaxRecoveryAccounts: Get<u32>;
+		/// The deposit reserved from an account initiating a recovery. It is returned when the
+		/// recovery is finalized or dropped, and slashed if the recovery is cancelled.
+		type RecoveryDeposit: Get<BalanceOf<Self>>;
+	}
+
+	/// The accounts that can help recover an identity, and how many of them must agree.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RecoveryConfig<BlockNumber, Friends> {
+		/// The accounts that can vouch for a recovery, sorted and without duplicates.
+		pub friends: Friends,
+		/// The number of vouches a recovery needs.
+		pub threshold: u16,
+		/// The number of blocks between a recovery being initiated and it being finalized,
+		/// during which the owner or a recovery account can cancel it. Never zero.
+		pub delay_period: BlockNumber,
+	}
+
+	/// A recovery in progress, moving an identity to a new owner.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ActiveRecovery<AccountId, BlockNumber, Friends, Balance> {
+		/// The account that will own the identity once the recovery is finalized.
+		pub new_owner: AccountId,
+		/// The block in which the recovery was initiated.
+		pub initiated_at: BlockNumber,
+		/// The recovery accounts that have vouched so far, sorted.
+		pub vouchers: Friends,
+		/// The deposit reserved from `new_owner` for the claim.
+		pub deposit: Balance,
+	}
+
+	/// The kind of value a registered trait key holds.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum TraitFormat {
+		/// Any bytes.
+		Bytes,
+		/// Valid UTF-8 text.
+		Utf8,
+		/// A non-negative decimal integer written in ASCII digits.
+		Integer,
+		/// An `http://` or `https://` URL with a host.
+		Url,
+		/// An email-like `local@domain.tld` address.
+		Email,
+	}
+
+	impl TraitFormat {
+		/// Whether `value` is well formed for this format.
+		pub fn accepts(&self, value: &[u8]) -> bool {
+			match self {
+				TraitFormat::Bytes => true,
+				TraitFormat::Utf8 => core::str::from_utf8(value).is_ok(),
+				TraitFormat::Integer => !value.is_empty() && value.iter().all(u8::is_ascii_digit),
+				TraitFormat::Url => core::str::from_utf8(value).is_ok_and(Self::is_url),
+				TraitFormat::Email => core::str::from_utf8(value).is_ok_and(Self::is_email),
+			}
+		}
+
+		fn is_url(text: &str) -> bool {
+			if text.contains(char::is_whitespace) {
+				return false;
+			}
+			let Some(rest) = text.strip_prefix("https://").or_else(|| text.strip_prefix("http://"))
+			else {
+				return false;
+			};
+			rest.split(|c| matches!(c, '/' | '?' | '#'))
+				.next()
+				.is_some_and(|host| !host.is_empty())
+		}
+
+		fn is_email(text: &str) -> bool {
+			if text.contains(char::is_whitespace) {
+				return false;
+			}
+			let Some((local, domain)) = text.split_once('@') else {
+				return false;
+			};
+			!local.is_empty() &&
+				domain.contains('.') &&
+				domain.split('.').all(|label| !label.is_empty() && !label.contains('@'))
+		}
+	}
+
+	/// The rules a registered trait key imposes on its values.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct TraitSchema {
+		/// The kind of value the key holds.
+		pub format: TraitFormat,
+		/// The maximum length of a value in bytes.
+		pub max_length: u32,
+	}
+
+	/// A third party's statement that an identity trait holds a particular value.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Attestation<Hash, BlockNumber, Balance> {
+		/// The hash of the trait value the attester vouched for.
+		pub value_hash: Hash,
+		/// The block in which the attestation was made.
+		pub attested_at: BlockNumber,
+		/// The deposit reserved from the attester for storing the attestation.
+		pub deposit: Balance,
 	}
 
+	/// A single change applied by `set_identity_traits`.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum TraitOperation<Trait> {
+		/// Adds or updates the trait with the given key and value.
+		Set(Trait, Trait),
+		/// Removes the trait with the given key.
+		Remove(Trait),
+	}
+
+	pub type TraitOperationOf<T> = TraitOperation<BoundedVec<u8, <T as Config>::MaxSize>>;
+
+	pub type AttestationOf<T> =
+		Attestation<<T as frame_system::Config>::Hash, BlockNumberFor<T>, BalanceOf<T>>;
+
+	pub type FriendsOf<T> =
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxRecoveryAccounts>;
+
+	pub type RecoveryConfigOf<T> = RecoveryConfig<BlockNumberFor<T>, FriendsOf<T>>;
+
+	pub type ActiveRecoveryOf<T> = ActiveRecovery<
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+		FriendsOf<T>,
+		BalanceOf<T>,
+	>;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::type_value]
@@ -56,6 +212,17 @@ pub mod pallet {
 	/// Maps accounts to the array of identities it owns.
 	pub type IdentityList<T: Config> = StorageMap<_, Blake2_128Concat, u32, T::AccountId>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn identities_by_owner)]
+	/// Maps accounts to the ID numbers of the identities they own.
+	pub type IdentitiesByOwner<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u32, T::MaxIdentitiesPerOwner>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn identity_trait_list)]
 	/// Maps identity ID numbers to their key/value attributes.
@@ -69,6 +236,71 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn trait_deposit)]
+	/// The account that paid for each identity trait and the deposit reserved from it, returned
+	/// when the trait is removed.
+	pub type TraitDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		(T::AccountId, BalanceOf<T>),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn trait_commitment)]
+	/// Maps identity ID numbers and trait keys to a salted hash commitment of a private trait
+	/// value, as built by `commitment_of`. A key holds either a plaintext trait in
+	/// `IdentityTraitList` or a commitment here, never both.
+	pub type TraitCommitments<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		T::Hash,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn trait_schema)]
+	/// Maps trait keys registered by governance to the rules their values must follow. Keys
+	/// without a schema accept any value.
+	pub type TraitSchemas<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxSize>, TraitSchema>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn trait_attestation)]
+	/// Maps an identity ID number, trait key and attester to the attestation it made. An
+	/// attestation only counts while the trait still holds the value it was made for, but its
+	/// deposit stays reserved until the attester withdraws it or the identity is cleared.
+	pub type TraitAttestations<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, u32>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::MaxSize>>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		AttestationOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_transfer)]
+	/// Maps identity ID numbers to the account their owner has offered them to.
+	pub type PendingTransfers<T: Config> = StorageMap<_, Blake2_128Concat, u32, T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn recovery_config)]
+	/// Maps identity ID numbers to the recovery accounts their owner has set up.
+	pub type RecoveryConfigs<T: Config> = StorageMap<_, Blake2_128Concat, u32, RecoveryConfigOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn active_recovery)]
+	/// Maps identity ID numbers to the recovery currently in progress for them.
+	pub type ActiveRecoveries<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, ActiveRecoveryOf<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -81,6 +313,55 @@ pub mod pallet {
 		/// Announce that an identity has been updated. Contains the ID number of the identity and
 		/// the owning AccountId.
 		IdentityUpdated(u32, T::AccountId),
+		/// Announce that an identity has been offered to another account. Contains the ID number
+		/// of the identity, the owning AccountId and the AccountId it was offered to.
+		IdentityTransferOffered(u32, T::AccountId, T::AccountId),
+		/// Announce that a pending identity transfer was cancelled. Contains the ID number of the
+		/// identity and the owning AccountId.
+		IdentityTransferCancelled(u32, T::AccountId),
+		/// Announce that an identity has changed owner. Contains the ID number of the identity,
+		/// the previous owner and the new owner.
+		IdentityTransferred(u32, T::AccountId, T::AccountId),
+		/// Announce that a trait has been attested. Contains the ID number of the identity, the
+		/// trait key and the attesting AccountId.
+		IdentityTraitAttested(u32, BoundedVec<u8, T::MaxSize>, T::AccountId),
+		/// Announce that an attestation has been withdrawn. Contains the ID number of the
+		/// identity, the trait key and the attesting AccountId.
+		AttestationRevoked(u32, BoundedVec<u8, T::MaxSize>, T::AccountId),
+		/// Announce that traits and attestations of a revoked identity have been cleared.
+		/// Contains the ID number of the identity and the number of entries removed.
+		IdentityTraitsCleared(u32, u32),
+		/// Announce that an identity has committed to a private trait value. Contains the ID
+		/// number of the identity, the trait key and the commitment.
+		IdentityTraitCommitted(u32, BoundedVec<u8, T::MaxSize>, T::Hash),
+		/// Announce that a committed trait value has been revealed and checked against its
+		/// commitment. Contains the ID number of the identity, the trait key and the revealing
+		/// AccountId.
+		IdentityTraitRevealed(u32, BoundedVec<u8, T::MaxSize>, T::AccountId),
+		/// Announce that a trait schema has been registered or updated. Contains the trait key
+		/// and the schema.
+		TraitSchemaRegistered(BoundedVec<u8, T::MaxSize>, TraitSchema),
+		/// Announce that a trait schema has been removed. Contains the trait key.
+		TraitSchemaRemoved(BoundedVec<u8, T::MaxSize>),
+		/// Announce that an identity's recovery accounts have been set. Contains the ID number
+		/// of the identity and the owning AccountId.
+		RecoveryConfigured(u32, T::AccountId),
+		/// Announce that an identity's recovery accounts have been removed. Contains the ID
+		/// number of the identity and the owning AccountId.
+		RecoveryConfigRemoved(u32, T::AccountId),
+		/// Announce that a recovery has been initiated. Contains the ID number of the identity
+		/// and the AccountId claiming it.
+		RecoveryInitiated(u32, T::AccountId),
+		/// Announce that a recovery account has vouched for a recovery. Contains the ID number
+		/// of the identity, the AccountId claiming it and the vouching AccountId.
+		RecoveryVouched(u32, T::AccountId, T::AccountId),
+		/// Announce that the owner or a recovery account has cancelled a recovery and slashed
+		/// the claimant's deposit. Contains the ID number of the identity and the cancelling
+		/// AccountId.
+		RecoveryCancelled(u32, T::AccountId),
+		/// Announce that an identity has been recovered. Contains the ID number of the identity,
+		/// the previous owner and the new owner.
+		IdentityRecovered(u32, T::AccountId, T::AccountId),
 	}
 
 	#[pallet::error]
@@ -90,6 +371,63 @@ pub mod pallet {
 		StorageOverflow,
 		/// The current account does not own the identity.
 		IdentityNotOwned,
+		/// The account cannot afford the deposit for storing the trait.
+		InsufficientBalance,
+		/// An identity cannot be offered to the account that already owns it.
+		CannotTransferToSelf,
+		/// The identity has no pending transfer.
+		NoPendingTransfer,
+		/// The pending transfer was offered to a different account.
+		NotTransferRecipient,
+		/// The identity has more traits than the given witness.
+		TraitCountWitnessTooLow,
+		/// The account already owns `MaxIdentitiesPerOwner` identities.
+		TooManyIdentities,
+		/// The identity does not exist.
+		IdentityNotFound,
+		/// The identity has no trait with the given key.
+		TraitNotFound,
+		/// The given hash does not match the trait's current value.
+		ValueHashMismatch,
+		/// Owners cannot attest their own traits.
+		CannotAttestOwnTrait,
+		/// The account has not attested the trait.
+		AttestationNotFound,
+		/// The identity has not been revoked.
+		IdentityNotRevoked,
+		/// The revoked identity has nothing left to clear.
+		NothingToClear,
+		/// The identity has no commitment for the given key.
+		CommitmentNotFound,
+		/// The revealed value and salt do not match the stored commitment.
+		CommitmentMismatch,
+		/// No schema is registered for the given key.
+		SchemaNotFound,
+		/// The value is longer than the key's schema allows.
+		TraitValueTooLong,
+		/// The value does not match the format of the key's schema.
+		InvalidTraitValue,
+		/// The recovery accounts must be sorted without duplicates, the threshold must be
+		/// between one and their number, and the delay period must not be zero.
+		InvalidRecoveryConfig,
+		/// The identity has no recovery accounts.
+		RecoveryNotConfigured,
+		/// The identity already has a recovery in progress.
+		RecoveryInProgress,
+		/// The identity has no recovery in progress.
+		NoActiveRecovery,
+		/// The recovery in progress is for a different account.
+		NotRecoveryClaimant,
+		/// The account is not one of the identity's recovery accounts.
+		NotRecoveryAccount,
+		/// The recovery account has already vouched for this recovery.
+		AlreadyVouched,
+		/// The recovery does not have enough vouches yet.
+		RecoveryThresholdNotMet,
+		/// The recovery's delay period has not passed yet.
+		RecoveryDelayNotElapsed,
+		/// The owner of an identity cannot initiate its recovery.
+		CannotRecoverOwnIdentity,
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -99,6 +437,233 @@ pub mod pallet {
 				Result::Err(_) => false,
 			}
 		}
+
+		/// Records `identity_id` as owned by `owner` in `IdentitiesByOwner`.
+		fn add_to_owner_index(owner: &T::AccountId, identity_id: u32) -> DispatchResult {
+			<IdentitiesByOwner<T>>::try_mutate(owner, |identities| {
+				identities
+					.try_push(identity_id)
+					.map_err(|_| Error::<T>::TooManyIdentities.into())
+			})
+		}
+
+		/// Removes `identity_id` from the identities `owner` holds in `IdentitiesByOwner`.
+		fn remove_from_owner_index(owner: &T::AccountId, identity_id: u32) {
+			<IdentitiesByOwner<T>>::mutate_exists(owner, |identities| {
+				if let Some(list) = identities {
+					list.retain(|id| *id != identity_id);
+					if list.is_empty() {
+						*identities = None;
+					}
+				}
+			});
+		}
+
+		/// Whether `attester` has vouched for the current value of the trait `key` on the
+		/// identity `identity_id`.
+		pub fn is_trait_attested_by(
+			identity_id: u32,
+			key: &BoundedVec<u8, T::MaxSize>,
+			attester: &T::AccountId,
+		) -> bool {
+			<TraitAttestations<T>>::get((identity_id, key, attester)).is_some_and(|attestation| {
+				Self::current_value_hash(identity_id, key) == Some(attestation.value_hash)
+			})
+		}
+
+		/// Lists the accounts that have vouched for the current value of the trait `key` on the
+		/// identity `identity_id`.
+		pub fn trait_attesters(identity_id: u32, key: &[u8]) -> Vec<T::AccountId> {
+			let Ok(key) = BoundedVec::<u8, T::MaxSize>::try_from(key.to_vec()) else {
+				return Vec::new();
+			};
+			let Some(value_hash) = Self::current_value_hash(identity_id, &key) else {
+				return Vec::new();
+			};
+			<TraitAttestations<T>>::iter_prefix((identity_id, key))
+				.filter(|(_, attestation)| attestation.value_hash == value_hash)
+				.map(|(attester, _)| attester)
+				.collect()
+		}
+
+		/// The hash of the value the trait `key` currently holds on the identity `identity_id`.
+		/// For a private trait this is the commitment itself.
+		fn current_value_hash(
+			identity_id: u32,
+			key: &BoundedVec<u8, T::MaxSize>,
+		) -> Option<T::Hash> {
+			<IdentityTraitList<T>>::try_get(identity_id, key)
+				.ok()
+				.map(|value| T::Hashing::hash(&value))
+				.or_else(|| <TraitCommitments<T>>::get(identity_id, key))
+		}
+
+		/// The commitment to a private trait `value` blinded with `salt`: the hash of the SCALE
+		/// encoded `(value, salt)` pair. The salt should be random and kept alongside the value,
+		/// since anyone holding both can prove the trait.
+		pub fn commitment_of(value: &[u8], salt: &[u8]) -> T::Hash {
+			T::Hashing::hash_of(&(value, salt))
+		}
+
+		/// Whether `value` and `salt` open the commitment stored for the trait `key` on the
+		/// identity `identity_id`.
+		pub fn verify_trait_commitment(
+			identity_id: u32,
+			key: &[u8],
+			value: &[u8],
+			salt: &[u8],
+		) -> bool {
+			let Ok(key) = BoundedVec::<u8, T::MaxSize>::try_from(key.to_vec()) else {
+				return false;
+			};
+			<TraitCommitments<T>>::get(identity_id, key)
+				.is_some_and(|commitment| commitment == Self::commitment_of(value, salt))
+		}
+
+		/// Stores `value` under `key` for the identity, re-pricing the deposit reserved from `who`.
+		fn set_trait(
+			who: &T::AccountId,
+			identity_id: u32,
+			key: BoundedVec<u8, T::MaxSize>,
+			value: BoundedVec<u8, T::MaxSize>,
+		) -> DispatchResult {
+			Self::validate_trait(&key, &value)?;
+
+			// The deposit is recalculated for the new value, so release whatever the previous value
+			// held before reserving again.
+			Self::refund_trait_deposit(identity_id, &key);
+			let deposit = Self::deposit_for(&key, &value);
+			T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			<TraitDeposits<T>>::insert(identity_id, &key, (who.clone(), deposit));
+
+			<TraitCommitments<T>>::remove(identity_id, &key);
+			<IdentityTraitList<T>>::insert(identity_id, key, value);
+			Ok(())
+		}
+
+		/// Hands the identity and the deposits of its traits from `previous_owner` to `new_owner`,
+		/// dropping any pending transfer and recovery. `trait_count` must be at least the number
+		/// of traits the identity holds.
+		fn transfer_ownership(
+			identity_id: u32,
+			previous_owner: &T::AccountId,
+			new_owner: &T::AccountId,
+			trait_count: u32,
+		) -> DispatchResult {
+			let deposits: Vec<_> = <TraitDeposits<T>>::iter_prefix(identity_id).collect();
+			ensure!(deposits.len() as u32 <= trait_count, Error::<T>::TraitCountWitnessTooLow);
+			for (key, (depositor, deposit)) in deposits {
+				T::Currency::reserve(new_owner, deposit)
+					.map_err(|_| Error::<T>::InsufficientBalance)?;
+				T::Currency::unreserve(&depositor, deposit);
+				<TraitDeposits<T>>::insert(identity_id, key, (new_owner.clone(), deposit));
+			}
+
+			// Recovery accounts were chosen by the previous owner, so they do not carry over.
+			<PendingTransfers<T>>::remove(identity_id);
+			<RecoveryConfigs<T>>::remove(identity_id);
+			Self::drop_recovery(identity_id);
+			<IdentityList<T>>::insert(identity_id, new_owner);
+			Self::remove_from_owner_index(previous_owner, identity_id);
+			Self::add_to_owner_index(new_owner, identity_id)
+		}
+
+		/// Removes the recovery in progress for `identity_id`, if any, and returns the deposit to
+		/// its claimant.
+		fn drop_recovery(identity_id: u32) {
+			if let Some(recovery) = <ActiveRecoveries<T>>::take(identity_id) {
+				T::Currency::unreserve(&recovery.new_owner, recovery.deposit);
+			}
+		}
+
+		/// Checks `value` against the schema registered for `key`, if any.
+		pub fn validate_trait(
+			key: &BoundedVec<u8, T::MaxSize>,
+			value: &BoundedVec<u8, T::MaxSize>,
+		) -> DispatchResult {
+			if let Some(schema) = <TraitSchemas<T>>::get(key) {
+				ensure!(value.len() as u32 <= schema.max_length, Error::<T>::TraitValueTooLong);
+				ensure!(schema.format.accepts(value), Error::<T>::InvalidTraitValue);
+			}
+			Ok(())
+		}
+
+		/// Stores `commitment` under `key` for the identity in place of a plaintext value,
+		/// re-pricing the deposit reserved from `who`.
+		fn commit_trait(
+			who: &T::AccountId,
+			identity_id: u32,
+			key: BoundedVec<u8, T::MaxSize>,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			Self::refund_trait_deposit(identity_id, &key);
+			let bytes = key.len().saturating_add(commitment.encoded_size()) as u32;
+			let deposit = T::DepositPerByte::get().saturating_mul(bytes.into());
+			T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			<TraitDeposits<T>>::insert(identity_id, &key, (who.clone(), deposit));
+
+			<IdentityTraitList<T>>::remove(identity_id, &key);
+			<TraitCommitments<T>>::insert(identity_id, key, commitment);
+			Ok(())
+		}
+
+		/// Removes the trait `key`, plaintext or committed, from the identity and returns its
+		/// deposit.
+		fn remove_trait(identity_id: u32, key: &BoundedVec<u8, T::MaxSize>) {
+			Self::refund_trait_deposit(identity_id, key);
+			<IdentityTraitList<T>>::remove(identity_id, key);
+			<TraitCommitments<T>>::remove(identity_id, key);
+		}
+
+		/// Removes up to `limit` traits, commitments and attestations left behind by a revoked
+		/// identity, returning trait and attestation deposits to whoever paid them. Traits go first, then
+		/// commitments, and attestations are cleared once neither remains. Returns the number of
+		/// entries removed.
+		fn clear_traits(identity_id: u32, limit: u32) -> u32 {
+			let mut removed = 0u32;
+			for (key, _) in <IdentityTraitList<T>>::drain_prefix(identity_id).take(limit as usize) {
+				Self::refund_trait_deposit(identity_id, &key);
+				removed.saturating_inc();
+			}
+			for (key, _) in <TraitCommitments<T>>::drain_prefix(identity_id)
+				.take(limit.saturating_sub(removed) as usize)
+			{
+				Self::refund_trait_deposit(identity_id, &key);
+				removed.saturating_inc();
+			}
+			for ((_, attester), attestation) in <TraitAttestations<T>>::drain_prefix((identity_id,))
+				.take(limit.saturating_sub(removed) as usize)
+			{
+				T::Currency::unreserve(&attester, attestation.deposit);
+				removed.saturating_inc();
+			}
+			removed
+		}
+
+		/// The deposit required to attest to the trait `key` with `value_hash`.
+		pub fn attestation_deposit_for(
+			key: &BoundedVec<u8, T::MaxSize>,
+			value_hash: &T::Hash,
+		) -> BalanceOf<T> {
+			let bytes = key.len().saturating_add(value_hash.encoded_size()) as u32;
+			T::DepositPerByte::get().saturating_mul(bytes.into())
+		}
+
+		/// The deposit required to store a trait with the given key and value.
+		pub fn deposit_for(
+			key: &BoundedVec<u8, T::MaxSize>,
+			value: &BoundedVec<u8, T::MaxSize>,
+		) -> BalanceOf<T> {
+			let bytes = key.len().saturating_add(value.len()) as u32;
+			T::DepositPerByte::get().saturating_mul(bytes.into())
+		}
+
+		/// Returns the deposit held for a trait, if any, to the account that paid it.
+		fn refund_trait_deposit(identity_id: u32, key: &BoundedVec<u8, T::MaxSize>) {
+			if let Some((depositor, deposit)) = <TraitDeposits<T>>::take(identity_id, key) {
+				T::Currency::unreserve(&depositor, deposit);
+			}
+		}
 	}
 
 	#[pallet::call]
@@ -123,6 +688,7 @@ pub mod pallet {
 				*owner = Some(who.clone());
 				Ok(())
 			})?;
+			Self::add_to_owner_index(&who, current_id)?;
 
 			<IdentityNumber<T>>::put(new_id);
 			Self::deposit_event(Event::IdentityCreated(current_id, who));
@@ -131,8 +697,11 @@ pub mod pallet {
 		}
 
 		/// Revokes the identity with ID number identity_id, as long as the identity is owned by
-		/// origin.
-		#[pallet::weight(T::WeightInfo::revoke_identity())]
+		/// origin. Up to `MaxTraitOperations` of its traits are cleared straight away, and any
+		/// left over can be cleared with `clear_identity_traits`.
+		#[pallet::weight(T::WeightInfo::revoke_identity().saturating_add(
+			T::WeightInfo::clear_identity_traits(T::MaxTraitOperations::get())
+		))]
 		#[pallet::call_index(1)]
 		pub fn revoke_identity(origin: OriginFor<T>, identity_id: u32) -> DispatchResult {
 			let who = ensure_signed(origin)?;
@@ -147,13 +716,23 @@ pub mod pallet {
 				*owner = None;
 				Ok(())
 			})?;
+			<PendingTransfers<T>>::remove(identity_id);
+			<RecoveryConfigs<T>>::remove(identity_id);
+			Self::drop_recovery(identity_id);
+			Self::remove_from_owner_index(&who, identity_id);
 
 			Self::deposit_event(Event::IdentityRevoked(identity_id, who));
 
+			let removed = Self::clear_traits(identity_id, T::MaxTraitOperations::get());
+			if removed > 0 {
+				Self::deposit_event(Event::IdentityTraitsCleared(identity_id, removed));
+			}
+
 			Ok(())
 		}
 
-		/// Add a new identity trait to identity_id with key/value.
+		/// Add a new identity trait to identity_id with key/value. If key has a registered
+		/// schema, value must follow it.
 		#[pallet::weight(T::WeightInfo::add_or_update_identity_trait())]
 		#[pallet::call_index(2)]
 		pub fn add_or_update_identity_trait(
@@ -169,10 +748,7 @@ pub mod pallet {
 				Error::<T>::IdentityNotOwned
 			);
 
-			<IdentityTraitList<T>>::try_mutate(identity_id, key, |v| -> DispatchResult {
-				*v = value;
-				Ok(())
-			})?;
+			Self::set_trait(&who, identity_id, key, value)?;
 
 			Self::deposit_event(Event::IdentityUpdated(identity_id, who));
 
@@ -194,10 +770,460 @@ pub mod pallet {
 				Error::<T>::IdentityNotOwned
 			);
 
-			<IdentityTraitList<T>>::remove(identity_id, key);
+			Self::remove_trait(identity_id, &key);
+			Self::deposit_event(Event::IdentityUpdated(identity_id, who));
+
+			Ok(())
+		}
+
+		/// Offers the identity with ID number identity_id to new_owner, as long as the identity is
+		/// owned by origin. The transfer takes effect once new_owner accepts it, and a new offer
+		/// replaces any previous one.
+		#[pallet::weight(T::WeightInfo::offer_identity_transfer())]
+		#[pallet::call_index(4)]
+		pub fn offer_identity_transfer(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Self::is_identity_owned_by_sender(&who, &identity_id),
+				Error::<T>::IdentityNotOwned
+			);
+			ensure!(who != new_owner, Error::<T>::CannotTransferToSelf);
+
+			<PendingTransfers<T>>::insert(identity_id, &new_owner);
+			Self::deposit_event(Event::IdentityTransferOffered(identity_id, who, new_owner));
+
+			Ok(())
+		}
+
+		/// Withdraws the pending transfer of the identity with ID number identity_id.
+		#[pallet::weight(T::WeightInfo::cancel_identity_transfer())]
+		#[pallet::call_index(5)]
+		pub fn cancel_identity_transfer(origin: OriginFor<T>, identity_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Self::is_identity_owned_by_sender(&who, &identity_id),
+				Error::<T>::IdentityNotOwned
+			);
+			ensure!(
+				<PendingTransfers<T>>::take(identity_id).is_some(),
+				Error::<T>::NoPendingTransfer
+			);
+			Self::deposit_event(Event::IdentityTransferCancelled(identity_id, who));
+
+			Ok(())
+		}
+
+		/// Accepts the identity with ID number identity_id offered to origin. Origin becomes the
+		/// owner of the identity and its traits, and takes over their deposits from whoever paid
+		/// them. trait_count must be at least the number of traits the identity holds. The
+		/// identity's recovery accounts are removed.
+		#[pallet::weight(T::WeightInfo::accept_identity_transfer(*trait_count))]
+		#[pallet::call_index(6)]
+		pub fn accept_identity_transfer(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			trait_count: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let recipient =
+				<PendingTransfers<T>>::get(identity_id).ok_or(Error::<T>::NoPendingTransfer)?;
+			ensure!(recipient == who, Error::<T>::NotTransferRecipient);
+			let previous_owner =
+				<IdentityList<T>>::get(identity_id).ok_or(Error::<T>::IdentityNotOwned)?;
+
+			Self::transfer_ownership(identity_id, &previous_owner, &who, trait_count)?;
+			Self::deposit_event(Event::IdentityTransferred(identity_id, previous_owner, who));
+
+			Ok(())
+		}
+
+		/// Vouches for the trait key on the identity with ID identity_id. value_hash must be the
+		/// hash of the trait's current value, or the commitment itself for a private trait, and
+		/// the attestation stops counting once the owner changes it. A deposit for the key and
+		/// hash is reserved from origin until the attestation is withdrawn.
+		#[pallet::weight(T::WeightInfo::attest_identity_trait())]
+		#[pallet::call_index(7)]
+		pub fn attest_identity_trait(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			key: BoundedVec<u8, T::MaxSize>,
+			value_hash: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let owner = <IdentityList<T>>::get(identity_id).ok_or(Error::<T>::IdentityNotFound)?;
+			ensure!(owner != who, Error::<T>::CannotAttestOwnTrait);
+			let current_hash =
+				Self::current_value_hash(identity_id, &key).ok_or(Error::<T>::TraitNotFound)?;
+			ensure!(current_hash == value_hash, Error::<T>::ValueHashMismatch);
+
+			let deposit = Self::attestation_deposit_for(&key, &value_hash);
+			if let Some(previous) = <TraitAttestations<T>>::get((identity_id, &key, &who)) {
+				T::Currency::unreserve(&who, previous.deposit);
+			}
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			<TraitAttestations<T>>::insert(
+				(identity_id, &key, &who),
+				Attestation {
+					value_hash,
+					attested_at: <frame_system::Pallet<T>>::block_number(),
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::IdentityTraitAttested(identity_id, key, who));
+
+			Ok(())
+		}
+
+		/// Withdraws the attestation origin made on the trait key of the identity with ID
+		/// identity_id and returns its deposit, whether or not the attestation still counts.
+		#[pallet::weight(T::WeightInfo::revoke_attestation())]
+		#[pallet::call_index(8)]
+		pub fn revoke_attestation(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			key: BoundedVec<u8, T::MaxSize>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let attestation = <TraitAttestations<T>>::take((identity_id, &key, &who))
+				.ok_or(Error::<T>::AttestationNotFound)?;
+			T::Currency::unreserve(&who, attestation.deposit);
+			Self::deposit_event(Event::AttestationRevoked(identity_id, key, who));
+
+			Ok(())
+		}
+
+		/// Applies a batch of trait operations to the identity with ID identity_id, as long as
+		/// the identity is owned by origin. Operations are applied in order, and the whole batch
+		/// fails if any of them does.
+		#[pallet::weight(T::WeightInfo::set_identity_traits(operations.len() as u32))]
+		#[pallet::call_index(9)]
+		pub fn set_identity_traits(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			operations: BoundedVec<TraitOperationOf<T>, T::MaxTraitOperations>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Self::is_identity_owned_by_sender(&who, &identity_id),
+				Error::<T>::IdentityNotOwned
+			);
+
+			for operation in operations {
+				match operation {
+					TraitOperation::Set(key, value) =>
+						Self::set_trait(&who, identity_id, key, value)?,
+					TraitOperation::Remove(key) => Self::remove_trait(identity_id, &key),
+				}
+			}
+
 			Self::deposit_event(Event::IdentityUpdated(identity_id, who));
 
 			Ok(())
 		}
+
+		/// Removes up to limit traits and attestations left behind by the revoked identity with
+		/// ID identity_id, returning trait deposits to whoever paid them. Anyone can call this.
+		#[pallet::weight(T::WeightInfo::clear_identity_traits(*limit))]
+		#[pallet::call_index(10)]
+		pub fn clear_identity_traits(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			limit: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(
+				identity_id < <IdentityNumber<T>>::get() &&
+					!<IdentityList<T>>::contains_key(identity_id),
+				Error::<T>::IdentityNotRevoked
+			);
+
+			let removed = Self::clear_traits(identity_id, limit);
+			ensure!(removed > 0, Error::<T>::NothingToClear);
+			Self::deposit_event(Event::IdentityTraitsCleared(identity_id, removed));
+
+			Ok(())
+		}
+
+		/// Stores a private trait on the identity with ID identity_id, as long as the identity is
+		/// owned by origin. Only the commitment, built off-chain with `commitment_of`, is stored, and
+		/// it replaces any plaintext value held under key. The value can later be disclosed to
+		/// anyone by handing them the value and salt.
+		#[pallet::weight(T::WeightInfo::commit_identity_trait())]
+		#[pallet::call_index(11)]
+		pub fn commit_identity_trait(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			key: BoundedVec<u8, T::MaxSize>,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Self::is_identity_owned_by_sender(&who, &identity_id),
+				Error::<T>::IdentityNotOwned
+			);
+
+			Self::commit_trait(&who, identity_id, key.clone(), commitment)?;
+			Self::deposit_event(Event::IdentityTraitCommitted(identity_id, key, commitment));
+
+			Ok(())
+		}
+
+		/// Proves on-chain that value and salt open the commitment stored for the trait key on
+		/// the identity with ID identity_id. Anyone holding the value and salt can call this, and
+		/// the commitment is left in place.
+		#[pallet::weight(T::WeightInfo::reveal_identity_trait())]
+		#[pallet::call_index(12)]
+		pub fn reveal_identity_trait(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			key: BoundedVec<u8, T::MaxSize>,
+			value: BoundedVec<u8, T::MaxSize>,
+			salt: BoundedVec<u8, T::MaxSize>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let commitment = <TraitCommitments<T>>::get(identity_id, &key)
+				.ok_or(Error::<T>::CommitmentNotFound)?;
+			ensure!(
+				commitment == Self::commitment_of(&value, &salt),
+				Error::<T>::CommitmentMismatch
+			);
+			Self::deposit_event(Event::IdentityTraitRevealed(identity_id, key, who));
+
+			Ok(())
+		}
+
+		/// Registers schema for the trait key, replacing any schema it already has. Values set
+		/// for key from now on must follow it, while existing values are left untouched.
+		#[pallet::weight(T::WeightInfo::register_trait_schema())]
+		#[pallet::call_index(13)]
+		pub fn register_trait_schema(
+			origin: OriginFor<T>,
+			key: BoundedVec<u8, T::MaxSize>,
+			schema: TraitSchema,
+		) -> DispatchResult {
+			T::PrivilegedOrigin::ensure_origin(origin)?;
+
+			<TraitSchemas<T>>::insert(&key, &schema);
+			Self::deposit_event(Event::TraitSchemaRegistered(key, schema));
+
+			Ok(())
+		}
+
+		/// Removes the schema of the trait key, so it accepts any value again.
+		#[pallet::weight(T::WeightInfo::remove_trait_schema())]
+		#[pallet::call_index(14)]
+		pub fn remove_trait_schema(
+			origin: OriginFor<T>,
+			key: BoundedVec<u8, T::MaxSize>,
+		) -> DispatchResult {
+			T::PrivilegedOrigin::ensure_origin(origin)?;
+
+			ensure!(<TraitSchemas<T>>::take(&key).is_some(), Error::<T>::SchemaNotFound);
+			Self::deposit_event(Event::TraitSchemaRemoved(key));
+
+			Ok(())
+		}
+
+		/// Sets the accounts that can recover the identity with ID identity_id if its owner loses
+		/// access, as long as the identity is owned by origin. friends must be sorted without
+		/// duplicates, and threshold of them must vouch for a recovery, which can be finalized
+		/// delay_period blocks after it was initiated.
+		#[pallet::weight(T::WeightInfo::set_recovery_config())]
+		#[pallet::call_index(15)]
+		pub fn set_recovery_config(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			friends: FriendsOf<T>,
+			threshold: u16,
+			delay_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Self::is_identity_owned_by_sender(&who, &identity_id),
+				Error::<T>::IdentityNotOwned
+			);
+			ensure!(
+				threshold >= 1 &&
+					threshold as usize <= friends.len() &&
+					friends.windows(2).all(|pair| pair[0] < pair[1]) &&
+					!delay_period.is_zero(),
+				Error::<T>::InvalidRecoveryConfig
+			);
+			ensure!(
+				!<ActiveRecoveries<T>>::contains_key(identity_id),
+				Error::<T>::RecoveryInProgress
+			);
+
+			<RecoveryConfigs<T>>::insert(
+				identity_id,
+				RecoveryConfig { friends, threshold, delay_period },
+			);
+			Self::deposit_event(Event::RecoveryConfigured(identity_id, who));
+
+			Ok(())
+		}
+
+		/// Removes the recovery accounts of the identity with ID identity_id, as long as the
+		/// identity is owned by origin and no recovery is in progress.
+		#[pallet::weight(T::WeightInfo::remove_recovery_config())]
+		#[pallet::call_index(16)]
+		pub fn remove_recovery_config(origin: OriginFor<T>, identity_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Self::is_identity_owned_by_sender(&who, &identity_id),
+				Error::<T>::IdentityNotOwned
+			);
+			ensure!(
+				!<ActiveRecoveries<T>>::contains_key(identity_id),
+				Error::<T>::RecoveryInProgress
+			);
+			ensure!(
+				<RecoveryConfigs<T>>::take(identity_id).is_some(),
+				Error::<T>::RecoveryNotConfigured
+			);
+			Self::deposit_event(Event::RecoveryConfigRemoved(identity_id, who));
+
+			Ok(())
+		}
+
+		/// Starts recovering the identity with ID identity_id to origin. The identity's recovery
+		/// accounts then vouch for origin with vouch_recovery. RecoveryDeposit is reserved from
+		/// origin until the recovery is finalized or dropped, and is slashed if the owner or a
+		/// recovery account cancels it.
+		#[pallet::weight(T::WeightInfo::initiate_recovery())]
+		#[pallet::call_index(17)]
+		pub fn initiate_recovery(origin: OriginFor<T>, identity_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let owner = <IdentityList<T>>::get(identity_id).ok_or(Error::<T>::IdentityNotFound)?;
+			ensure!(owner != who, Error::<T>::CannotRecoverOwnIdentity);
+			ensure!(
+				<RecoveryConfigs<T>>::contains_key(identity_id),
+				Error::<T>::RecoveryNotConfigured
+			);
+			ensure!(
+				!<ActiveRecoveries<T>>::contains_key(identity_id),
+				Error::<T>::RecoveryInProgress
+			);
+			let deposit = T::RecoveryDeposit::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			<ActiveRecoveries<T>>::insert(
+				identity_id,
+				ActiveRecovery {
+					new_owner: who.clone(),
+					initiated_at: <frame_system::Pallet<T>>::block_number(),
+					vouchers: BoundedVec::default(),
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::RecoveryInitiated(identity_id, who));
+
+			Ok(())
+		}
+
+		/// Vouches, as one of its recovery accounts, that the identity with ID identity_id should
+		/// be recovered to new_owner.
+		#[pallet::weight(T::WeightInfo::vouch_recovery())]
+		#[pallet::call_index(18)]
+		pub fn vouch_recovery(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let config =
+				<RecoveryConfigs<T>>::get(identity_id).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(config.friends.binary_search(&who).is_ok(), Error::<T>::NotRecoveryAccount);
+
+			<ActiveRecoveries<T>>::try_mutate(identity_id, |recovery| -> DispatchResult {
+				let recovery = recovery.as_mut().ok_or(Error::<T>::NoActiveRecovery)?;
+				ensure!(recovery.new_owner == new_owner, Error::<T>::NotRecoveryClaimant);
+				match recovery.vouchers.binary_search(&who) {
+					Ok(_) => Err(Error::<T>::AlreadyVouched.into()),
+					Err(index) => recovery
+						.vouchers
+						.try_insert(index, who.clone())
+						.map_err(|_| Error::<T>::StorageOverflow.into()),
+				}
+			})?;
+			Self::deposit_event(Event::RecoveryVouched(identity_id, new_owner, who));
+
+			Ok(())
+		}
+
+		/// Completes the recovery of the identity with ID identity_id to origin, once enough
+		/// recovery accounts have vouched and the delay period has passed. Origin becomes the
+		/// owner of the identity and takes over the deposits of its traits, and its recovery
+		/// deposit is returned. trait_count must be at least the number of traits the identity
+		/// holds.
+		#[pallet::weight(T::WeightInfo::finalize_recovery(*trait_count))]
+		#[pallet::call_index(19)]
+		pub fn finalize_recovery(
+			origin: OriginFor<T>,
+			identity_id: u32,
+			trait_count: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let recovery =
+				<ActiveRecoveries<T>>::get(identity_id).ok_or(Error::<T>::NoActiveRecovery)?;
+			ensure!(recovery.new_owner == who, Error::<T>::NotRecoveryClaimant);
+			let config =
+				<RecoveryConfigs<T>>::get(identity_id).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(
+				recovery.vouchers.len() >= config.threshold as usize,
+				Error::<T>::RecoveryThresholdNotMet
+			);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >=
+					recovery.initiated_at.saturating_add(config.delay_period),
+				Error::<T>::RecoveryDelayNotElapsed
+			);
+			let previous_owner =
+				<IdentityList<T>>::get(identity_id).ok_or(Error::<T>::IdentityNotFound)?;
+
+			Self::transfer_ownership(identity_id, &previous_owner, &who, trait_count)?;
+			Self::deposit_event(Event::IdentityRecovered(identity_id, previous_owner, who));
+
+			Ok(())
+		}
+
+		/// Cancels the recovery in progress for the identity with ID identity_id and slashes the
+		/// claimant's deposit, as long as origin still owns the identity or is one of its
+		/// recovery accounts.
+		#[pallet::weight(T::WeightInfo::cancel_recovery())]
+		#[pallet::call_index(20)]
+		pub fn cancel_recovery(origin: OriginFor<T>, identity_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if !Self::is_identity_owned_by_sender(&who, &identity_id) {
+				let config =
+					<RecoveryConfigs<T>>::get(identity_id).ok_or(Error::<T>::IdentityNotOwned)?;
+				ensure!(config.friends.binary_search(&who).is_ok(), Error::<T>::NotRecoveryAccount);
+			}
+			let recovery =
+				<ActiveRecoveries<T>>::take(identity_id).ok_or(Error::<T>::NoActiveRecovery)?;
+			let _ = T::Currency::slash_reserved(&recovery.new_owner, recovery.deposit);
+			Self::deposit_event(Event::RecoveryCancelled(identity_id, who));
+
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,71 @@
+//! Storage migrations for the identity pallet.
+
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+
+use crate::{Config, Pallet};
+
+pub mod v1 {
+	use super::*;
+	use crate::{IdentitiesByOwner, IdentityList};
+
+	/// Builds the `IdentitiesByOwner` index from the identities already in `IdentityList`.
+	/// Identities beyond `MaxIdentitiesPerOwner` for a single owner stay owned but are left out
+	/// of the index.
+	pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut identities = 0u64;
+			for (identity_id, owner) in IdentityList::<T>::iter() {
+				identities += 1;
+				IdentitiesByOwner::<T>::mutate(&owner, |owned| {
+					if !owned.contains(&identity_id) {
+						let _ = owned.try_push(identity_id);
+					}
+				});
+			}
+			T::DbWeight::get().reads_writes(identities.saturating_mul(2), identities)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((IdentityList::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(IdentityList::<T>::iter().count() as u32 == count, "identities were lost");
+			for (owner, owned) in IdentitiesByOwner::<T>::iter() {
+				for identity_id in owned {
+					ensure!(
+						IdentityList::<T>::get(identity_id).as_ref() == Some(&owner),
+						"owner index lists an identity the account does not own"
+					);
+				}
+			}
+			for (identity_id, owner) in IdentityList::<T>::iter() {
+				let owned = IdentitiesByOwner::<T>::get(&owner);
+				ensure!(
+					owned.contains(&identity_id) || owned.is_full(),
+					"owner index is incomplete"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrates the identity pallet from storage version 0 to 1.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
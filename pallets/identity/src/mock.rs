@@ -7,6 +7,8 @@ use sp_runtime::{
 	BuildStorage,
 };
 
+pub type Balance = u128;
+
 type Block = frame_system::mocking::MockBlock<Test>;
 
 // Configure a mock runtime to test the pallet.
@@ -15,6 +17,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		IdentityModule: pallet_identity::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
@@ -41,7 +44,7 @@ impl system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -50,16 +53,50 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 1;
+	pub const DepositPerByte: u128 = 1;
+	pub const RecoveryDeposit: u128 = 100;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = [u8; 8];
+	type MaxFreezes = ();
+	type RuntimeFreezeReason = ();
+}
+
 impl pallet_identity::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxSize = ConstU32<1024>;
+	type Currency = Balances;
+	type DepositPerByte = DepositPerByte;
+	type MaxIdentitiesPerOwner = ConstU32<10>;
+	type MaxTraitOperations = ConstU32<64>;
+	type PrivilegedOrigin = frame_system::EnsureRoot<u64>;
+	type MaxRecoveryAccounts = ConstU32<5>;
+	type RecoveryDeposit = RecoveryDeposit;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	RuntimeGenesisConfig { system: Default::default() }
-		.build_storage()
-		.unwrap()
-		.into()
+	RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: pallet_balances::GenesisConfig {
+			balances: vec![(1, 10_000), (2, 10_000), (3, 10_000), (200, 10_000), (300, 10_000)],
+		},
+	}
+	.build_storage()
+	.unwrap()
+	.into()
 }
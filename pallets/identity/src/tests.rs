@@ -1,6 +1,14 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use crate::{
+	migrations, mock::*, Error, IdentityList, IdentityTraitList, TraitFormat, TraitOperation,
+	TraitSchema,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
 use sp_core::ConstU32;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 
 #[test]
 fn issue_identity() {
@@ -170,3 +178,934 @@ fn remove_identity_trait() {
 		System::assert_last_event(crate::Event::IdentityUpdated(0, 300).into());
 	});
 }
+
+#[test]
+fn identity_trait_deposits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = BoundedVec::<u8, ConstU32<1024>>::try_from("name".as_bytes().to_vec()).unwrap();
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke Skywalker".as_bytes().to_vec())
+			.unwrap();
+		let leia = BoundedVec::<u8, ConstU32<1024>>::try_from("Leia".as_bytes().to_vec()).unwrap();
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+
+		// The deposit covers every byte of the key and value.
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			key.clone(),
+			luke
+		));
+		assert_eq!(Balances::reserved_balance(300), 18);
+		assert_eq!(IdentityModule::trait_deposit(0, key.clone()), Some((300, 18)));
+
+		// Updating a trait re-prices the deposit for the new value.
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			key.clone(),
+			leia.clone()
+		));
+		assert_eq!(Balances::reserved_balance(300), 8);
+
+		assert_ok!(IdentityModule::remove_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			key.clone()
+		));
+		assert_eq!(Balances::reserved_balance(300), 0);
+		assert_eq!(Balances::free_balance(300), 10_000);
+		assert_eq!(IdentityModule::trait_deposit(0, key.clone()), None);
+
+		// Accounts that cannot cover the deposit cannot add traits.
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(4)));
+		assert_noop!(
+			IdentityModule::add_or_update_identity_trait(RuntimeOrigin::signed(4), 1, key, leia),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn identity_transfer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = BoundedVec::<u8, ConstU32<1024>>::try_from("name".as_bytes().to_vec()).unwrap();
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke Skywalker".as_bytes().to_vec())
+			.unwrap();
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			key.clone(),
+			luke.clone()
+		));
+
+		assert_noop!(
+			IdentityModule::offer_identity_transfer(RuntimeOrigin::signed(200), 0, 200),
+			Error::<Test>::IdentityNotOwned
+		);
+		assert_noop!(
+			IdentityModule::offer_identity_transfer(RuntimeOrigin::signed(300), 0, 300),
+			Error::<Test>::CannotTransferToSelf
+		);
+		assert_ok!(IdentityModule::offer_identity_transfer(RuntimeOrigin::signed(300), 0, 200));
+		System::assert_last_event(crate::Event::IdentityTransferOffered(0, 300, 200).into());
+		assert_eq!(IdentityModule::pending_transfer(0), Some(200));
+
+		assert_noop!(
+			IdentityModule::accept_identity_transfer(RuntimeOrigin::signed(3), 0, 1),
+			Error::<Test>::NotTransferRecipient
+		);
+		assert_noop!(
+			IdentityModule::accept_identity_transfer(RuntimeOrigin::signed(200), 0, 0),
+			Error::<Test>::TraitCountWitnessTooLow
+		);
+		assert_ok!(IdentityModule::accept_identity_transfer(RuntimeOrigin::signed(200), 0, 1));
+		System::assert_last_event(crate::Event::IdentityTransferred(0, 300, 200).into());
+
+		// The new owner holds the identity, its traits and their deposits.
+		assert_eq!(IdentityModule::identity_list(0), Some(200));
+		assert_eq!(IdentityModule::pending_transfer(0), None);
+		assert_eq!(IdentityModule::identity_trait_list(0, key.clone()), luke);
+		assert_eq!(IdentityModule::trait_deposit(0, key.clone()), Some((200, 18)));
+		assert_eq!(Balances::reserved_balance(300), 0);
+		assert_eq!(Balances::reserved_balance(200), 18);
+		assert_noop!(
+			IdentityModule::remove_identity_trait(RuntimeOrigin::signed(300), 0, key.clone()),
+			Error::<Test>::IdentityNotOwned
+		);
+		assert_ok!(IdentityModule::remove_identity_trait(RuntimeOrigin::signed(200), 0, key));
+	});
+}
+
+#[test]
+fn cancel_identity_transfer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_noop!(
+			IdentityModule::cancel_identity_transfer(RuntimeOrigin::signed(300), 0),
+			Error::<Test>::NoPendingTransfer
+		);
+		assert_ok!(IdentityModule::offer_identity_transfer(RuntimeOrigin::signed(300), 0, 200));
+		assert_ok!(IdentityModule::cancel_identity_transfer(RuntimeOrigin::signed(300), 0));
+		System::assert_last_event(crate::Event::IdentityTransferCancelled(0, 300).into());
+		assert_noop!(
+			IdentityModule::accept_identity_transfer(RuntimeOrigin::signed(200), 0, 0),
+			Error::<Test>::NoPendingTransfer
+		);
+
+		// Revoking an identity drops its pending transfer.
+		assert_ok!(IdentityModule::offer_identity_transfer(RuntimeOrigin::signed(300), 0, 200));
+		assert_ok!(IdentityModule::revoke_identity(RuntimeOrigin::signed(300), 0));
+		assert_noop!(
+			IdentityModule::accept_identity_transfer(RuntimeOrigin::signed(200), 0, 0),
+			Error::<Test>::NoPendingTransfer
+		);
+	});
+}
+
+#[test]
+fn identities_by_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(200)));
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_eq!(IdentityModule::identities_by_owner(300).into_inner(), vec![0, 2]);
+		assert_eq!(IdentityModule::identities_by_owner(200).into_inner(), vec![1]);
+
+		assert_ok!(IdentityModule::revoke_identity(RuntimeOrigin::signed(300), 0));
+		assert_eq!(IdentityModule::identities_by_owner(300).into_inner(), vec![2]);
+
+		// Transfers move the identity between the owners' lists.
+		assert_ok!(IdentityModule::offer_identity_transfer(RuntimeOrigin::signed(300), 2, 200));
+		assert_ok!(IdentityModule::accept_identity_transfer(RuntimeOrigin::signed(200), 2, 0));
+		assert!(IdentityModule::identities_by_owner(300).is_empty());
+		assert_eq!(IdentityModule::identities_by_owner(200).into_inner(), vec![1, 2]);
+	});
+}
+
+#[test]
+fn too_many_identities() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for _ in 0..10 {
+			assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		}
+		assert_noop!(
+			IdentityModule::create_identity(RuntimeOrigin::signed(300)),
+			Error::<Test>::TooManyIdentities
+		);
+
+		// Accepting a transfer counts towards the limit too.
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(200)));
+		assert_ok!(IdentityModule::offer_identity_transfer(RuntimeOrigin::signed(200), 10, 300));
+		assert_noop!(
+			IdentityModule::accept_identity_transfer(RuntimeOrigin::signed(300), 10, 0),
+			Error::<Test>::TooManyIdentities
+		);
+	});
+}
+
+#[test]
+fn attest_identity_trait() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = BoundedVec::<u8, ConstU32<1024>>::try_from("name".as_bytes().to_vec()).unwrap();
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke Skywalker".as_bytes().to_vec())
+			.unwrap();
+		let leia = BoundedVec::<u8, ConstU32<1024>>::try_from("Leia".as_bytes().to_vec()).unwrap();
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+
+		assert_noop!(
+			IdentityModule::attest_identity_trait(
+				RuntimeOrigin::signed(200),
+				0,
+				key.clone(),
+				BlakeTwo256::hash(&luke)
+			),
+			Error::<Test>::TraitNotFound
+		);
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			key.clone(),
+			luke.clone()
+		));
+		assert_noop!(
+			IdentityModule::attest_identity_trait(
+				RuntimeOrigin::signed(300),
+				0,
+				key.clone(),
+				BlakeTwo256::hash(&luke)
+			),
+			Error::<Test>::CannotAttestOwnTrait
+		);
+		assert_noop!(
+			IdentityModule::attest_identity_trait(
+				RuntimeOrigin::signed(200),
+				0,
+				key.clone(),
+				BlakeTwo256::hash(&leia)
+			),
+			Error::<Test>::ValueHashMismatch
+		);
+		assert_noop!(
+			IdentityModule::attest_identity_trait(
+				RuntimeOrigin::signed(200),
+				1,
+				key.clone(),
+				BlakeTwo256::hash(&luke)
+			),
+			Error::<Test>::IdentityNotFound
+		);
+
+		assert_ok!(IdentityModule::attest_identity_trait(
+			RuntimeOrigin::signed(200),
+			0,
+			key.clone(),
+			BlakeTwo256::hash(&luke)
+		));
+		System::assert_last_event(crate::Event::IdentityTraitAttested(0, key.clone(), 200).into());
+		assert!(IdentityModule::is_trait_attested_by(0, &key, &200));
+		assert_eq!(IdentityModule::trait_attesters(0, &key), vec![200]);
+		// The attester pays for the key and the 32-byte hash.
+		assert_eq!(Balances::reserved_balance(200), 36);
+
+		// Attesting again replaces the attestation without reserving twice.
+		assert_ok!(IdentityModule::attest_identity_trait(
+			RuntimeOrigin::signed(200),
+			0,
+			key.clone(),
+			BlakeTwo256::hash(&luke)
+		));
+		assert_eq!(Balances::reserved_balance(200), 36);
+
+		// Changing the value invalidates the attestation.
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			key.clone(),
+			leia
+		));
+		assert!(!IdentityModule::is_trait_attested_by(0, &key, &200));
+		assert!(IdentityModule::trait_attesters(0, &key).is_empty());
+
+		// Restoring the attested value makes it count again until it is revoked.
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			key.clone(),
+			luke
+		));
+		assert!(IdentityModule::is_trait_attested_by(0, &key, &200));
+		assert_ok!(IdentityModule::revoke_attestation(RuntimeOrigin::signed(200), 0, key.clone()));
+		System::assert_last_event(crate::Event::AttestationRevoked(0, key.clone(), 200).into());
+		assert!(!IdentityModule::is_trait_attested_by(0, &key, &200));
+		assert_eq!(Balances::reserved_balance(200), 0);
+		assert_noop!(
+			IdentityModule::revoke_attestation(RuntimeOrigin::signed(200), 0, key),
+			Error::<Test>::AttestationNotFound
+		);
+	});
+}
+
+fn bounded(value: &str) -> BoundedVec<u8, ConstU32<1024>> {
+	BoundedVec::try_from(value.as_bytes().to_vec()).unwrap()
+}
+
+#[test]
+fn set_identity_traits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			bounded("name"),
+			bounded("Luke")
+		));
+
+		let operations = BoundedVec::try_from(vec![
+			TraitOperation::Remove(bounded("name")),
+			TraitOperation::Set(bounded("planet"), bounded("Tatooine")),
+			TraitOperation::Set(bounded("rank"), bounded("Jedi")),
+		])
+		.unwrap();
+		assert_noop!(
+			IdentityModule::set_identity_traits(RuntimeOrigin::signed(200), 0, operations.clone()),
+			Error::<Test>::IdentityNotOwned
+		);
+		assert_ok!(IdentityModule::set_identity_traits(RuntimeOrigin::signed(300), 0, operations));
+		System::assert_last_event(crate::Event::IdentityUpdated(0, 300).into());
+
+		assert!(!IdentityTraitList::<Test>::contains_key(0, bounded("name")));
+		assert_eq!(IdentityModule::identity_trait_list(0, bounded("planet")), bounded("Tatooine"));
+		assert_eq!(IdentityModule::identity_trait_list(0, bounded("rank")), bounded("Jedi"));
+		assert_eq!(Balances::reserved_balance(300), 22);
+
+		// A failing operation rolls back the whole batch.
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(4)));
+		let _ = Balances::deposit_creating(&4, 5);
+		let operations = BoundedVec::try_from(vec![
+			TraitOperation::Set(bounded("a"), bounded("b")),
+			TraitOperation::Set(bounded("name"), bounded("Obi-Wan Kenobi")),
+		])
+		.unwrap();
+		assert_noop!(
+			IdentityModule::set_identity_traits(RuntimeOrigin::signed(4), 1, operations),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn revoke_identity_clears_traits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			bounded("name"),
+			bounded("Luke")
+		));
+		assert_ok!(IdentityModule::attest_identity_trait(
+			RuntimeOrigin::signed(200),
+			0,
+			bounded("name"),
+			BlakeTwo256::hash(&bounded("Luke"))
+		));
+
+		assert_noop!(
+			IdentityModule::clear_identity_traits(RuntimeOrigin::signed(200), 0, 10),
+			Error::<Test>::IdentityNotRevoked
+		);
+		assert_ok!(IdentityModule::revoke_identity(RuntimeOrigin::signed(300), 0));
+		System::assert_last_event(crate::Event::IdentityTraitsCleared(0, 2).into());
+		assert!(!IdentityTraitList::<Test>::contains_key(0, bounded("name")));
+		assert_eq!(IdentityModule::trait_deposit(0, bounded("name")), None);
+		assert_eq!(IdentityModule::trait_attestation((0, bounded("name"), 200)), None);
+		assert_eq!(Balances::reserved_balance(300), 0);
+		assert_eq!(Balances::reserved_balance(200), 0);
+		assert_noop!(
+			IdentityModule::clear_identity_traits(RuntimeOrigin::signed(200), 0, 10),
+			Error::<Test>::NothingToClear
+		);
+		// Identities that were never created cannot be cleared.
+		assert_noop!(
+			IdentityModule::clear_identity_traits(RuntimeOrigin::signed(200), 1, 10),
+			Error::<Test>::IdentityNotRevoked
+		);
+	});
+}
+
+#[test]
+fn clear_identity_traits_after_revocation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		for i in 0..70 {
+			assert_ok!(IdentityModule::add_or_update_identity_trait(
+				RuntimeOrigin::signed(300),
+				0,
+				bounded(&format!("key{}", i)),
+				bounded("value")
+			));
+		}
+
+		// Revocation clears `MaxTraitOperations` traits and leaves the rest.
+		assert_ok!(IdentityModule::revoke_identity(RuntimeOrigin::signed(300), 0));
+		System::assert_last_event(crate::Event::IdentityTraitsCleared(0, 64).into());
+		assert_eq!(IdentityTraitList::<Test>::iter_prefix(0).count(), 6);
+
+		assert_ok!(IdentityModule::clear_identity_traits(RuntimeOrigin::signed(200), 0, 4));
+		System::assert_last_event(crate::Event::IdentityTraitsCleared(0, 4).into());
+		assert_ok!(IdentityModule::clear_identity_traits(RuntimeOrigin::signed(200), 0, 4));
+		System::assert_last_event(crate::Event::IdentityTraitsCleared(0, 2).into());
+		assert_eq!(IdentityTraitList::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(Balances::reserved_balance(300), 0);
+	});
+}
+
+#[test]
+fn commit_identity_trait() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			bounded("age"),
+			bounded("19")
+		));
+		let commitment = IdentityModule::commitment_of(b"19", b"salt");
+		assert_eq!(commitment, BlakeTwo256::hash_of(&(b"19".as_slice(), b"salt".as_slice())));
+
+		assert_noop!(
+			IdentityModule::commit_identity_trait(
+				RuntimeOrigin::signed(200),
+				0,
+				bounded("age"),
+				commitment
+			),
+			Error::<Test>::IdentityNotOwned
+		);
+		assert_ok!(IdentityModule::commit_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			bounded("age"),
+			commitment
+		));
+		System::assert_last_event(
+			crate::Event::IdentityTraitCommitted(0, bounded("age"), commitment).into(),
+		);
+
+		// The commitment replaces the plaintext value and its deposit covers the key and hash.
+		assert!(!IdentityTraitList::<Test>::contains_key(0, bounded("age")));
+		assert_eq!(IdentityModule::trait_commitment(0, bounded("age")), Some(commitment));
+		assert_eq!(Balances::reserved_balance(300), 35);
+
+		// Setting a plaintext value replaces the commitment again.
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			bounded("age"),
+			bounded("19")
+		));
+		assert_eq!(IdentityModule::trait_commitment(0, bounded("age")), None);
+		assert_eq!(Balances::reserved_balance(300), 5);
+	});
+}
+
+#[test]
+fn reveal_identity_trait() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_noop!(
+			IdentityModule::reveal_identity_trait(
+				RuntimeOrigin::signed(200),
+				0,
+				bounded("age"),
+				bounded("19"),
+				bounded("salt")
+			),
+			Error::<Test>::CommitmentNotFound
+		);
+		assert_ok!(IdentityModule::commit_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			bounded("age"),
+			IdentityModule::commitment_of(b"19", b"salt")
+		));
+
+		assert!(IdentityModule::verify_trait_commitment(0, b"age", b"19", b"salt"));
+		assert!(!IdentityModule::verify_trait_commitment(0, b"age", b"20", b"salt"));
+		assert!(!IdentityModule::verify_trait_commitment(0, b"age", b"19", b"pepper"));
+		assert!(!IdentityModule::verify_trait_commitment(0, b"name", b"19", b"salt"));
+
+		assert_noop!(
+			IdentityModule::reveal_identity_trait(
+				RuntimeOrigin::signed(200),
+				0,
+				bounded("age"),
+				bounded("20"),
+				bounded("salt")
+			),
+			Error::<Test>::CommitmentMismatch
+		);
+		assert_ok!(IdentityModule::reveal_identity_trait(
+			RuntimeOrigin::signed(200),
+			0,
+			bounded("age"),
+			bounded("19"),
+			bounded("salt")
+		));
+		System::assert_last_event(
+			crate::Event::IdentityTraitRevealed(0, bounded("age"), 200).into(),
+		);
+		assert!(IdentityModule::trait_commitment(0, bounded("age")).is_some());
+	});
+}
+
+#[test]
+fn attestation_requires_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			bounded("name"),
+			bounded("Luke")
+		));
+
+		assert_noop!(
+			IdentityModule::attest_identity_trait(
+				RuntimeOrigin::signed(4),
+				0,
+				bounded("name"),
+				BlakeTwo256::hash(&bounded("Luke"))
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn committed_traits_can_be_attested_and_cleared() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		let commitment = IdentityModule::commitment_of(b"19", b"salt");
+		assert_ok!(IdentityModule::commit_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			bounded("age"),
+			commitment
+		));
+
+		// Attesters vouch for the commitment without learning the value.
+		assert_ok!(IdentityModule::attest_identity_trait(
+			RuntimeOrigin::signed(200),
+			0,
+			bounded("age"),
+			commitment
+		));
+		assert!(IdentityModule::is_trait_attested_by(0, &bounded("age"), &200));
+
+		assert_ok!(IdentityModule::revoke_identity(RuntimeOrigin::signed(300), 0));
+		System::assert_last_event(crate::Event::IdentityTraitsCleared(0, 2).into());
+		assert_eq!(IdentityModule::trait_commitment(0, bounded("age")), None);
+		assert_eq!(IdentityModule::trait_attestation((0, bounded("age"), 200)), None);
+		assert_eq!(Balances::reserved_balance(300), 0);
+		assert_eq!(Balances::reserved_balance(200), 0);
+	});
+}
+
+#[test]
+fn register_trait_schema() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let schema = TraitSchema { format: TraitFormat::Email, max_length: 64 };
+		assert_noop!(
+			IdentityModule::register_trait_schema(
+				RuntimeOrigin::signed(1),
+				bounded("email"),
+				schema.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(IdentityModule::register_trait_schema(
+			RuntimeOrigin::root(),
+			bounded("email"),
+			schema.clone()
+		));
+		System::assert_last_event(
+			crate::Event::TraitSchemaRegistered(bounded("email"), schema.clone()).into(),
+		);
+		assert_eq!(IdentityModule::trait_schema(bounded("email")), Some(schema));
+
+		assert_noop!(
+			IdentityModule::remove_trait_schema(RuntimeOrigin::signed(1), bounded("email")),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(IdentityModule::remove_trait_schema(RuntimeOrigin::root(), bounded("email")));
+		System::assert_last_event(crate::Event::TraitSchemaRemoved(bounded("email")).into());
+		assert_eq!(IdentityModule::trait_schema(bounded("email")), None);
+		assert_noop!(
+			IdentityModule::remove_trait_schema(RuntimeOrigin::root(), bounded("email")),
+			Error::<Test>::SchemaNotFound
+		);
+	});
+}
+
+#[test]
+fn trait_schema_is_enforced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::register_trait_schema(
+			RuntimeOrigin::root(),
+			bounded("email"),
+			TraitSchema { format: TraitFormat::Email, max_length: 16 }
+		));
+
+		assert_noop!(
+			IdentityModule::add_or_update_identity_trait(
+				RuntimeOrigin::signed(300),
+				0,
+				bounded("email"),
+				bounded("luke")
+			),
+			Error::<Test>::InvalidTraitValue
+		);
+		assert_noop!(
+			IdentityModule::add_or_update_identity_trait(
+				RuntimeOrigin::signed(300),
+				0,
+				bounded("email"),
+				bounded("luke@tatooine.example")
+			),
+			Error::<Test>::TraitValueTooLong
+		);
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			bounded("email"),
+			bounded("luke@jedi.org")
+		));
+
+		// Batches are checked too, and keys without a schema accept anything.
+		let operations = BoundedVec::try_from(vec![
+			TraitOperation::Set(bounded("nickname"), bounded("")),
+			TraitOperation::Set(bounded("email"), bounded("luke@")),
+		])
+		.unwrap();
+		assert_noop!(
+			IdentityModule::set_identity_traits(RuntimeOrigin::signed(300), 0, operations),
+			Error::<Test>::InvalidTraitValue
+		);
+	});
+}
+
+#[test]
+fn trait_formats() {
+	assert!(TraitFormat::Bytes.accepts(&[0xff, 0x00]));
+	assert!(TraitFormat::Utf8.accepts("Tatooine".as_bytes()));
+	assert!(!TraitFormat::Utf8.accepts(&[0xff, 0x00]));
+
+	assert!(TraitFormat::Integer.accepts(b"19"));
+	assert!(!TraitFormat::Integer.accepts(b""));
+	assert!(!TraitFormat::Integer.accepts(b"-19"));
+
+	assert!(TraitFormat::Url.accepts(b"https://fennellabs.com/about"));
+	assert!(TraitFormat::Url.accepts(b"http://localhost"));
+	assert!(!TraitFormat::Url.accepts(b"ftp://fennellabs.com"));
+	assert!(!TraitFormat::Url.accepts(b"https:///path"));
+	assert!(!TraitFormat::Url.accepts(b"https://fennel labs.com"));
+
+	assert!(TraitFormat::Email.accepts(b"luke@jedi.org"));
+	assert!(!TraitFormat::Email.accepts(b"luke@jedi"));
+	assert!(!TraitFormat::Email.accepts(b"@jedi.org"));
+	assert!(!TraitFormat::Email.accepts(b"luke@jedi..org"));
+	assert!(!TraitFormat::Email.accepts(b"luke@skywalker@jedi.org"));
+}
+
+fn friends(accounts: Vec<u64>) -> BoundedVec<u64, ConstU32<5>> {
+	BoundedVec::try_from(accounts).unwrap()
+}
+
+#[test]
+fn set_recovery_config() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+
+		assert_noop!(
+			IdentityModule::set_recovery_config(
+				RuntimeOrigin::signed(200),
+				0,
+				friends(vec![1, 2, 3]),
+				2,
+				10
+			),
+			Error::<Test>::IdentityNotOwned
+		);
+		for (accounts, threshold) in
+			[(vec![1, 2, 3], 0), (vec![1, 2, 3], 4), (vec![2, 1, 3], 2), (vec![1, 1, 3], 2)]
+		{
+			assert_noop!(
+				IdentityModule::set_recovery_config(
+					RuntimeOrigin::signed(300),
+					0,
+					friends(accounts),
+					threshold,
+					10
+				),
+				Error::<Test>::InvalidRecoveryConfig
+			);
+		}
+		// A recovery must leave the owner time to cancel it.
+		assert_noop!(
+			IdentityModule::set_recovery_config(
+				RuntimeOrigin::signed(300),
+				0,
+				friends(vec![1, 2, 3]),
+				2,
+				0
+			),
+			Error::<Test>::InvalidRecoveryConfig
+		);
+
+		assert_ok!(IdentityModule::set_recovery_config(
+			RuntimeOrigin::signed(300),
+			0,
+			friends(vec![1, 2, 3]),
+			2,
+			10
+		));
+		System::assert_last_event(crate::Event::RecoveryConfigured(0, 300).into());
+		assert_eq!(IdentityModule::recovery_config(0).unwrap().threshold, 2);
+
+		assert_ok!(IdentityModule::remove_recovery_config(RuntimeOrigin::signed(300), 0));
+		System::assert_last_event(crate::Event::RecoveryConfigRemoved(0, 300).into());
+		assert_eq!(IdentityModule::recovery_config(0), None);
+		assert_noop!(
+			IdentityModule::remove_recovery_config(RuntimeOrigin::signed(300), 0),
+			Error::<Test>::RecoveryNotConfigured
+		);
+	});
+}
+
+#[test]
+fn recover_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::add_or_update_identity_trait(
+			RuntimeOrigin::signed(300),
+			0,
+			bounded("name"),
+			bounded("Luke")
+		));
+		assert_noop!(
+			IdentityModule::initiate_recovery(RuntimeOrigin::signed(200), 0),
+			Error::<Test>::RecoveryNotConfigured
+		);
+		assert_ok!(IdentityModule::set_recovery_config(
+			RuntimeOrigin::signed(300),
+			0,
+			friends(vec![1, 2, 3]),
+			2,
+			10
+		));
+
+		assert_noop!(
+			IdentityModule::initiate_recovery(RuntimeOrigin::signed(300), 0),
+			Error::<Test>::CannotRecoverOwnIdentity
+		);
+		assert_ok!(IdentityModule::initiate_recovery(RuntimeOrigin::signed(200), 0));
+		System::assert_last_event(crate::Event::RecoveryInitiated(0, 200).into());
+		assert_eq!(IdentityModule::active_recovery(0).unwrap().deposit, 100);
+		assert_eq!(Balances::reserved_balance(200), 100);
+		assert_noop!(
+			IdentityModule::initiate_recovery(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::RecoveryInProgress
+		);
+		// The recovery accounts cannot be changed while a recovery is in progress.
+		assert_noop!(
+			IdentityModule::set_recovery_config(
+				RuntimeOrigin::signed(300),
+				0,
+				friends(vec![1]),
+				1,
+				1
+			),
+			Error::<Test>::RecoveryInProgress
+		);
+
+		assert_noop!(
+			IdentityModule::vouch_recovery(RuntimeOrigin::signed(200), 0, 200),
+			Error::<Test>::NotRecoveryAccount
+		);
+		assert_noop!(
+			IdentityModule::vouch_recovery(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::NotRecoveryClaimant
+		);
+		assert_ok!(IdentityModule::vouch_recovery(RuntimeOrigin::signed(1), 0, 200));
+		System::assert_last_event(crate::Event::RecoveryVouched(0, 200, 1).into());
+		assert_noop!(
+			IdentityModule::vouch_recovery(RuntimeOrigin::signed(1), 0, 200),
+			Error::<Test>::AlreadyVouched
+		);
+		assert_noop!(
+			IdentityModule::finalize_recovery(RuntimeOrigin::signed(200), 0, 1),
+			Error::<Test>::RecoveryThresholdNotMet
+		);
+		assert_ok!(IdentityModule::vouch_recovery(RuntimeOrigin::signed(3), 0, 200));
+
+		assert_noop!(
+			IdentityModule::finalize_recovery(RuntimeOrigin::signed(3), 0, 1),
+			Error::<Test>::NotRecoveryClaimant
+		);
+		assert_noop!(
+			IdentityModule::finalize_recovery(RuntimeOrigin::signed(200), 0, 1),
+			Error::<Test>::RecoveryDelayNotElapsed
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			IdentityModule::finalize_recovery(RuntimeOrigin::signed(200), 0, 0),
+			Error::<Test>::TraitCountWitnessTooLow
+		);
+		assert_ok!(IdentityModule::finalize_recovery(RuntimeOrigin::signed(200), 0, 1));
+		System::assert_last_event(crate::Event::IdentityRecovered(0, 300, 200).into());
+
+		assert_eq!(IdentityModule::identity_list(0), Some(200));
+		assert_eq!(IdentityModule::identities_by_owner(300).len(), 0);
+		assert_eq!(IdentityModule::identities_by_owner(200).into_inner(), vec![0]);
+		assert_eq!(IdentityModule::trait_deposit(0, bounded("name")), Some((200, 8)));
+		assert_eq!(Balances::reserved_balance(300), 0);
+		// The recovery deposit is returned once the recovery is finalized.
+		assert_eq!(Balances::reserved_balance(200), 8);
+		// The previous owner's recovery accounts do not carry over.
+		assert_eq!(IdentityModule::recovery_config(0), None);
+		assert_eq!(IdentityModule::active_recovery(0), None);
+	});
+}
+
+#[test]
+fn cancel_recovery() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::set_recovery_config(
+			RuntimeOrigin::signed(300),
+			0,
+			friends(vec![1]),
+			1,
+			1
+		));
+		assert_noop!(
+			IdentityModule::cancel_recovery(RuntimeOrigin::signed(300), 0),
+			Error::<Test>::NoActiveRecovery
+		);
+		assert_ok!(IdentityModule::initiate_recovery(RuntimeOrigin::signed(200), 0));
+		assert_ok!(IdentityModule::vouch_recovery(RuntimeOrigin::signed(1), 0, 200));
+
+		assert_noop!(
+			IdentityModule::cancel_recovery(RuntimeOrigin::signed(200), 0),
+			Error::<Test>::NotRecoveryAccount
+		);
+		assert_ok!(IdentityModule::cancel_recovery(RuntimeOrigin::signed(300), 0));
+		System::assert_last_event(crate::Event::RecoveryCancelled(0, 300).into());
+		assert_noop!(
+			IdentityModule::finalize_recovery(RuntimeOrigin::signed(200), 0, 0),
+			Error::<Test>::NoActiveRecovery
+		);
+		assert_eq!(IdentityModule::identity_list(0), Some(300));
+		assert!(IdentityModule::recovery_config(0).is_some());
+		// Cancelling slashes the claimant's deposit.
+		assert_eq!(Balances::reserved_balance(200), 0);
+		assert_eq!(Balances::free_balance(200), 10_000 - 100);
+	});
+}
+
+#[test]
+fn recovery_account_can_cancel_stale_recovery() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::set_recovery_config(
+			RuntimeOrigin::signed(300),
+			0,
+			friends(vec![1, 2]),
+			2,
+			10
+		));
+		assert_ok!(IdentityModule::initiate_recovery(RuntimeOrigin::signed(200), 0));
+
+		// A recovery account can clear a claim nobody vouches for, so the owner losing their
+		// key does not leave the identity unrecoverable.
+		assert_noop!(
+			IdentityModule::cancel_recovery(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotRecoveryAccount
+		);
+		assert_ok!(IdentityModule::cancel_recovery(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(crate::Event::RecoveryCancelled(0, 2).into());
+		assert_eq!(IdentityModule::active_recovery(0), None);
+		assert_eq!(Balances::total_balance(&200), 10_000 - 100);
+
+		assert_ok!(IdentityModule::initiate_recovery(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(crate::Event::RecoveryInitiated(0, 3).into());
+	});
+}
+
+#[test]
+fn revoking_identity_returns_recovery_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityModule::create_identity(RuntimeOrigin::signed(300)));
+		assert_ok!(IdentityModule::set_recovery_config(
+			RuntimeOrigin::signed(300),
+			0,
+			friends(vec![1]),
+			1,
+			10
+		));
+		assert_ok!(IdentityModule::initiate_recovery(RuntimeOrigin::signed(200), 0));
+		assert_eq!(Balances::reserved_balance(200), 100);
+
+		assert_ok!(IdentityModule::revoke_identity(RuntimeOrigin::signed(300), 0));
+		assert_eq!(IdentityModule::active_recovery(0), None);
+		assert_eq!(Balances::reserved_balance(200), 0);
+		assert_eq!(Balances::free_balance(200), 10_000);
+	});
+}
+
+#[test]
+fn migrate_owner_index() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<IdentityModule>();
+		// Identities created before the owner index existed are only in `IdentityList`.
+		for (identity_id, owner) in [(0u32, 1u64), (1, 2), (2, 1)] {
+			IdentityList::<Test>::insert(identity_id, owner);
+		}
+
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(IdentityModule::on_chain_storage_version(), 1);
+		let mut owned = IdentityModule::identities_by_owner(1).into_inner();
+		owned.sort();
+		assert_eq!(owned, vec![0, 2]);
+		assert_eq!(IdentityModule::identities_by_owner(2).into_inner(), vec![1]);
+
+		// The migration only runs from version 0.
+		IdentityList::<Test>::insert(3, 3);
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert!(IdentityModule::identities_by_owner(3).is_empty());
+	});
+}
@@ -37,6 +37,23 @@ pub trait WeightInfo {
 	fn remove_identity_trait() -> Weight;
 	fn remove_identity_trait_heavy_storage() -> Weight;
 	fn remove_long_identity_trait() -> Weight;
+	fn offer_identity_transfer() -> Weight;
+	fn cancel_identity_transfer() -> Weight;
+	fn accept_identity_transfer(n: u32) -> Weight;
+	fn attest_identity_trait() -> Weight;
+	fn revoke_attestation() -> Weight;
+	fn set_identity_traits(n: u32) -> Weight;
+	fn clear_identity_traits(n: u32) -> Weight;
+	fn commit_identity_trait() -> Weight;
+	fn reveal_identity_trait() -> Weight;
+	fn register_trait_schema() -> Weight;
+	fn remove_trait_schema() -> Weight;
+	fn set_recovery_config() -> Weight;
+	fn remove_recovery_config() -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn vouch_recovery() -> Weight;
+	fn finalize_recovery(n: u32) -> Weight;
+	fn cancel_recovery() -> Weight;
 }
 
 /// Weights for pallet_identity using the Substrate node and recommended hardware.
@@ -47,87 +64,461 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityList` (r:1 w:1)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentitiesByOwner` (r:1 w:1)
+	/// Proof: `Identity::IdentitiesByOwner` (`max_values`: None, `max_size`: Some(450), added:
+	/// 2925, mode: `MaxEncodedLen`)
 	fn create_identity() -> Weight {
-		Weight::from_parts(25_000_000, 3517)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(25_000_000, 3915)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:1)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentitiesByOwner` (r:1 w:1)
+	/// Proof: `Identity::IdentitiesByOwner` (`max_values`: None, `max_size`: Some(450), added:
+	/// 2925, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added:
+	/// 2822, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
 	fn revoke_identity() -> Weight {
-		Weight::from_parts(13_000_000, 3517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(13_000_000, 3915)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:1)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentitiesByOwner` (r:1 w:1)
+	/// Proof: `Identity::IdentitiesByOwner` (`max_values`: None, `max_size`: Some(450), added:
+	/// 2925, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added:
+	/// 2822, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
 	fn revoke_identity_heavy_storage() -> Weight {
-		Weight::from_parts(24_000_000, 3517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(24_000_000, 3915)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:1 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitSchemas` (r:1 w:0)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
 	fn add_or_update_identity_trait() -> Weight {
 		Weight::from_parts(14_000_000, 5553)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:1 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitSchemas` (r:1 w:0)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
 	fn add_or_update_long_identity_trait() -> Weight {
 		Weight::from_parts(17_000_000, 5553)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:1 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitSchemas` (r:1 w:0)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
 	fn add_or_update_many_identity_traits() -> Weight {
 		Weight::from_parts(47_000_000, 5553)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:0 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
 	fn remove_identity_trait() -> Weight {
 		Weight::from_parts(14_000_000, 3517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:0 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
 	fn remove_identity_trait_heavy_storage() -> Weight {
 		Weight::from_parts(41_000_000, 3517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:0 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
 	fn remove_long_identity_trait() -> Weight {
 		Weight::from_parts(16_000_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingTransfers` (r:0 w:1)
+	/// Proof: `Identity::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	fn offer_identity_transfer() -> Weight {
+		Weight::from_parts(13_000_000, 3517)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingTransfers` (r:1 w:1)
+	/// Proof: `Identity::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	fn cancel_identity_transfer() -> Weight {
+		Weight::from_parts(14_000_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::PendingTransfers` (r:1 w:1)
+	/// Proof: `Identity::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityList` (r:1 w:1)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1001 w:1000)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1002 w:1002)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	/// Storage: `Identity::IdentitiesByOwner` (r:2 w:2)
+	/// Proof: `Identity::IdentitiesByOwner` (`max_values`: None, `max_size`: Some(450), added:
+	/// 2925, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added:
+	/// 2822, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	fn accept_identity_transfer(n: u32) -> Weight {
+		Weight::from_parts(20_000_000, 3915)
+			.saturating_add(Weight::from_parts(19_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3587).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityTraitList` (r:1 w:0)
+	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
+	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitAttestations` (r:1 w:1)
+	/// Proof: `Identity::TraitAttestations` (`max_values`: None, `max_size`: Some(1162), added:
+	/// 3637, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	fn attest_identity_trait() -> Weight {
+		Weight::from_parts(19_000_000, 5553)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Identity::TraitAttestations` (r:1 w:1)
+	/// Proof: `Identity::TraitAttestations` (`max_values`: None, `max_size`: Some(1162), added:
+	/// 3637, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	fn revoke_attestation() -> Weight {
+		Weight::from_parts(14_000_000, 4627)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:64 w:64)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityTraitList` (r:0 w:64)
+	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
+	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:64)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitSchemas` (r:64 w:0)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	fn set_identity_traits(n: u32) -> Weight {
+		Weight::from_parts(18_000_000, 3517)
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3587).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Identity::IdentityNumber` (r:1 w:0)
+	/// Proof: `Identity::IdentityNumber` (`max_values`: None, `max_size`: Some(4), added: 499,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityTraitList` (r:1001 w:1000)
+	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
+	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1000 w:1000)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitAttestations` (r:1 w:0)
+	/// Proof: `Identity::TraitAttestations` (`max_values`: None, `max_size`: Some(1162), added:
+	/// 3637, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:1 w:0)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn clear_identity_traits(n: u32) -> Weight {
+		Weight::from_parts(20_000_000, 4627)
+			.saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 4563).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityTraitList` (r:0 w:1)
+	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
+	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	fn commit_identity_trait() -> Weight {
+		Weight::from_parts(17_000_000, 4577)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Identity::TraitCommitments` (r:1 w:0)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	fn reveal_identity_trait() -> Weight {
+		Weight::from_parts(15_000_000, 4559).saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Identity::TraitSchemas` (r:0 w:1)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
+	fn register_trait_schema() -> Weight {
+		Weight::from_parts(11_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::TraitSchemas` (r:1 w:1)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
+	fn remove_trait_schema() -> Weight {
+		Weight::from_parts(14_000_000, 4512)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	fn set_recovery_config() -> Weight {
+		Weight::from_parts(16_000_000, 3858)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:1 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	fn remove_recovery_config() -> Weight {
+		Weight::from_parts(16_000_000, 3858)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(17_000_000, 3858)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Identity::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	fn vouch_recovery() -> Weight {
+		Weight::from_parts(18_000_000, 3858)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:1 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityList` (r:1 w:1)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1001 w:1000)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1002 w:1002)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::IdentitiesByOwner` (r:2 w:2)
+	/// Proof: `Identity::IdentitiesByOwner` (`max_values`: None, `max_size`: Some(450), added:
+	/// 2925, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingTransfers` (r:0 w:1)
+	/// Proof: `Identity::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn finalize_recovery(n: u32) -> Weight {
+		Weight::from_parts(24_000_000, 3915)
+			.saturating_add(Weight::from_parts(19_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3587).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		Weight::from_parts(15_000_000, 3858)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -137,121 +528,563 @@ impl WeightInfo for () {
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityList` (r:1 w:1)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentitiesByOwner` (r:1 w:1)
+	/// Proof: `Identity::IdentitiesByOwner` (`max_values`: None, `max_size`: Some(450), added:
+	/// 2925, mode: `MaxEncodedLen`)
 	fn create_identity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1190`
-		//  Estimated: `3517`
+		//  Estimated: `3915`
 		// Minimum execution time: 21_000_000 picoseconds.
-		Weight::from_parts(25_000_000, 3517)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(25_000_000, 3915)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:1)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentitiesByOwner` (r:1 w:1)
+	/// Proof: `Identity::IdentitiesByOwner` (`max_values`: None, `max_size`: Some(450), added:
+	/// 2925, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added:
+	/// 2822, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
 	fn revoke_identity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `108`
-		//  Estimated: `3517`
+		//  Estimated: `3915`
 		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(13_000_000, 3517)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(13_000_000, 3915)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:1)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentitiesByOwner` (r:1 w:1)
+	/// Proof: `Identity::IdentitiesByOwner` (`max_values`: None, `max_size`: Some(450), added:
+	/// 2925, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added:
+	/// 2822, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
 	fn revoke_identity_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1150`
-		//  Estimated: `3517`
+		//  Estimated: `3915`
 		// Minimum execution time: 18_000_000 picoseconds.
-		Weight::from_parts(24_000_000, 3517)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(24_000_000, 3915)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:1 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitSchemas` (r:1 w:0)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
 	fn add_or_update_identity_trait() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `108`
 		//  Estimated: `5553`
 		// Minimum execution time: 13_000_000 picoseconds.
 		Weight::from_parts(14_000_000, 5553)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:1 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitSchemas` (r:1 w:0)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
 	fn add_or_update_long_identity_trait() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `108`
 		//  Estimated: `5553`
 		// Minimum execution time: 16_000_000 picoseconds.
 		Weight::from_parts(17_000_000, 5553)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:1 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitSchemas` (r:1 w:0)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
 	fn add_or_update_many_identity_traits() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `715`
 		//  Estimated: `5553`
 		// Minimum execution time: 42_000_000 picoseconds.
 		Weight::from_parts(47_000_000, 5553)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:0 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
 	fn remove_identity_trait() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `142`
 		//  Estimated: `3517`
 		// Minimum execution time: 13_000_000 picoseconds.
 		Weight::from_parts(14_000_000, 3517)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:0 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
 	fn remove_identity_trait_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `142`
 		//  Estimated: `3517`
 		// Minimum execution time: 36_000_000 picoseconds.
 		Weight::from_parts(41_000_000, 3517)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::IdentityList` (r:1 w:0)
 	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
 	/// mode: `MaxEncodedLen`) Storage: `Identity::IdentityTraitList` (r:0 w:1)
 	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
 	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
 	fn remove_long_identity_trait() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `142`
 		//  Estimated: `3517`
 		// Minimum execution time: 15_000_000 picoseconds.
 		Weight::from_parts(16_000_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingTransfers` (r:0 w:1)
+	/// Proof: `Identity::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	fn offer_identity_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116`
+		//  Estimated: `3517`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3517)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingTransfers` (r:1 w:1)
+	/// Proof: `Identity::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	fn cancel_identity_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `3517`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::PendingTransfers` (r:1 w:1)
+	/// Proof: `Identity::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityList` (r:1 w:1)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1001 w:1000)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1002 w:1002)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	/// Storage: `Identity::IdentitiesByOwner` (r:2 w:2)
+	/// Proof: `Identity::IdentitiesByOwner` (`max_values`: None, `max_size`: Some(450), added:
+	/// 2925, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added:
+	/// 2822, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	fn accept_identity_transfer(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `3915`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3915)
+			.saturating_add(Weight::from_parts(19_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3587).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityTraitList` (r:1 w:0)
+	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
+	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitAttestations` (r:1 w:1)
+	/// Proof: `Identity::TraitAttestations` (`max_values`: None, `max_size`: Some(1162), added:
+	/// 3637, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	fn attest_identity_trait() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1267`
+		//  Estimated: `5553`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 5553)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Identity::TraitAttestations` (r:1 w:1)
+	/// Proof: `Identity::TraitAttestations` (`max_values`: None, `max_size`: Some(1162), added:
+	/// 3637, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	fn revoke_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1190`
+		//  Estimated: `4627`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 4627)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:64 w:64)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityTraitList` (r:0 w:64)
+	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
+	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:64)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitSchemas` (r:64 w:0)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	fn set_identity_traits(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1190`
+		//  Estimated: `3517`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3517)
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3587).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Identity::IdentityNumber` (r:1 w:0)
+	/// Proof: `Identity::IdentityNumber` (`max_values`: None, `max_size`: Some(4), added: 499,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityTraitList` (r:1001 w:1000)
+	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
+	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1000 w:1000)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::TraitAttestations` (r:1 w:0)
+	/// Proof: `Identity::TraitAttestations` (`max_values`: None, `max_size`: Some(1162), added:
+	/// 3637, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:1 w:0)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn clear_identity_traits(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1190`
+		//  Estimated: `4627`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 4627)
+			.saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 4563).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1 w:1)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityTraitList` (r:0 w:1)
+	/// Proof: `Identity::IdentityTraitList` (`max_values`: None, `max_size`: Some(2088), added:
+	/// 4563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitCommitments` (r:0 w:1)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	fn commit_identity_trait() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1112`
+		//  Estimated: `4577`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 4577)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Identity::TraitCommitments` (r:1 w:0)
+	/// Proof: `Identity::TraitCommitments` (`max_values`: None, `max_size`: Some(1094), added:
+	/// 3569, mode: `MaxEncodedLen`)
+	fn reveal_identity_trait() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1094`
+		//  Estimated: `4559`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4559).saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Identity::TraitSchemas` (r:0 w:1)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
+	fn register_trait_schema() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::TraitSchemas` (r:1 w:1)
+	/// Proof: `Identity::TraitSchemas` (`max_values`: None, `max_size`: Some(1047), added: 3522,
+	/// mode: `MaxEncodedLen`)
+	fn remove_trait_schema() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1047`
+		//  Estimated: `4512`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 4512)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	fn set_recovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `3858`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3858)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:1 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	fn remove_recovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `3858`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3858)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	fn initiate_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `3858`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3858)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Identity::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	fn vouch_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `3858`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3858)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:1 w:1)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityList` (r:1 w:1)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::TraitDeposits` (r:1001 w:1000)
+	/// Proof: `Identity::TraitDeposits` (`max_values`: None, `max_size`: Some(1112), added: 3587,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1002 w:1002)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Identity::IdentitiesByOwner` (r:2 w:2)
+	/// Proof: `Identity::IdentitiesByOwner` (`max_values`: None, `max_size`: Some(450), added:
+	/// 2925, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingTransfers` (r:0 w:1)
+	/// Proof: `Identity::PendingTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn finalize_recovery(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `450`
+		//  Estimated: `3915`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 3915)
+			.saturating_add(Weight::from_parts(19_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3587).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Identity::IdentityList` (r:1 w:0)
+	/// Proof: `Identity::IdentityList` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Identity::ActiveRecoveries` (`max_values`: None, `max_size`: Some(393), added:
+	/// 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Identity::RecoveryConfigs` (`max_values`: None, `max_size`: Some(347), added: 2822,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `3858`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3858)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
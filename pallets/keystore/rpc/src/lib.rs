@@ -0,0 +1,126 @@
+//! RPC interface for the keystore pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_keystore_runtime_api::KeystoreApi as KeystoreRuntimeApi;
+use pallet_keystore_runtime_api::{EncryptionKeyRecord, KeyDetails};
+
+/// Keystore RPC methods.
+#[rpc(client, server)]
+pub trait KeystoreApi<BlockHash, AccountId, BlockNumber> {
+	/// Lists every key currently announced by `account`.
+	#[method(name = "keystore_keys")]
+	fn keys(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<KeyDetails<BlockNumber>>>;
+
+	/// Returns the encryption key `account` currently wants to be reached with, and its ID.
+	#[method(name = "keystore_encryptionKey")]
+	fn encryption_key(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(u32, EncryptionKeyRecord<BlockNumber>)>>;
+
+	/// Checks whether `fingerprint` is announced by `account` and has not expired.
+	#[method(name = "keystore_isKeyValid")]
+	fn is_key_valid(
+		&self,
+		account: AccountId,
+		fingerprint: Bytes,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+}
+
+/// Provides RPC methods to query the keystore pallet.
+pub struct Keystore<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Keystore<C, Block> {
+	/// Creates a new instance of the keystore RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(e: ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the keystore.",
+		Some(e.to_string()),
+	)
+}
+
+impl<C, Block, AccountId, BlockNumber>
+	KeystoreApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber> for Keystore<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: KeystoreRuntimeApi<Block, AccountId, BlockNumber>,
+	AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+	fn keys(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<KeyDetails<BlockNumber>>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().keys(at_hash, account).map_err(runtime_error)
+	}
+
+	fn encryption_key(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<(u32, EncryptionKeyRecord<BlockNumber>)>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.encryption_key(at_hash, account)
+			.map_err(runtime_error)
+	}
+
+	fn is_key_valid(
+		&self,
+		account: AccountId,
+		fingerprint: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.is_key_valid(at_hash, account, fingerprint.to_vec())
+			.map_err(runtime_error)
+	}
+}
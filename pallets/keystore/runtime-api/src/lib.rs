@@ -0,0 +1,26 @@
+//! Runtime API definition for the keystore pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+pub use pallet_keystore::{EncryptionKeyRecord, KeyDetails};
+
+sp_api::decl_runtime_apis! {
+	/// Lookups into the keys announced through the keystore pallet.
+	pub trait KeystoreApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Every key currently announced by `account`.
+		fn keys(account: AccountId) -> Vec<KeyDetails<BlockNumber>>;
+		/// The encryption key `account` currently wants to be reached with, and its ID.
+		fn encryption_key(account: AccountId) -> Option<(u32, EncryptionKeyRecord<BlockNumber>)>;
+		/// Whether `fingerprint` is announced by `account` and has not expired.
+		fn is_key_valid(account: AccountId, fingerprint: Vec<u8>) -> bool;
+	}
+}
@@ -5,9 +5,19 @@ use super::*;
 use crate::Pallet as Keystore;
 
 use frame_benchmarking::{account as benchmark_account, v2::*};
-use frame_support::BoundedVec;
-use frame_system::RawOrigin;
-use scale_info::prelude::{format, vec};
+use frame_support::{
+	traits::{Currency, Get, Hooks},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use scale_info::prelude::{format, vec, vec::Vec};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+	traits::{Bounded, Saturating},
+	MultiSignature, MultiSigner,
+};
+
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"fnks");
 
 pub fn get_origin<T: Config>(name: &'static str) -> RawOrigin<T::AccountId> {
 	RawOrigin::Signed(get_account::<T>(name))
@@ -17,12 +27,38 @@ pub fn get_account<T: Config>(name: &'static str) -> T::AccountId {
 	benchmark_account(name, 0, 0)
 }
 
+/// Appoints the largest allowed guardian set for `owner`, all of whom must approve a recovery.
+pub fn set_max_guardians<T: Config>(owner: &'static str) -> Vec<T::AccountId> {
+	let guardians: Vec<T::AccountId> = (0..T::MaxGuardians::get())
+		.map(|i| benchmark_account("guardian", i, 0))
+		.collect();
+	Keystore::<T>::set_guardians(
+		get_origin::<T>(owner).into(),
+		BoundedVec::try_from(guardians.clone()).unwrap(),
+		T::MaxGuardians::get(),
+	)
+	.unwrap();
+	guardians
+}
+
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Gives `name` enough funds to cover the deposits of every key a benchmark announces.
+pub fn fund_account<T: Config>(name: &'static str) {
+	T::Currency::make_free_balance_be(
+		&get_account::<T>(name),
+		BalanceOf::<T>::max_value() / 2u32.into(),
+	);
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
 	fn announce_key() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let origin = get_origin::<T>("Anakin");
 		let location = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(
 			"location".as_bytes().to_vec(),
@@ -32,18 +68,29 @@ mod benchmarks {
 			"fingerprint".as_bytes().to_vec(),
 		)
 		.unwrap();
+		let expires_at: BlockNumberFor<T> = 10u32.into();
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
 
 		#[extrinsic_call]
-		_(origin.clone(), fingerprint.clone(), location.clone());
+		_(
+			origin.clone(),
+			fingerprint.clone(),
+			location.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			Some(expires_at),
+		);
 
 		let origin_address = get_account::<T>("Anakin");
 		assert_eq!(IssuedKeys::<T>::get(&origin_address, &fingerprint), Some(location));
+		assert_eq!(KeyExpiry::<T>::get(&origin_address, &fingerprint), Some(expires_at));
 
 		Ok(())
 	}
 
 	#[benchmark]
 	fn announce_a_whole_lotta_keys() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let origin = get_origin::<T>("Anakin");
 		let location = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(
 			"location".as_bytes().to_vec(),
@@ -66,11 +113,25 @@ mod benchmarks {
 			)
 			.unwrap();
 
-			Keystore::<T>::announce_key(origin.into(), fingerprint, location)?;
+			Keystore::<T>::announce_key(
+				origin.into(),
+				fingerprint,
+				location,
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				None,
+			)?;
 		}
 
 		#[extrinsic_call]
-		announce_key(origin.clone(), fingerprint.clone(), location.clone());
+		announce_key(
+			origin.clone(),
+			fingerprint.clone(),
+			location.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None,
+		);
 
 		let origin_address = get_account::<T>("Anakin");
 		assert_eq!(IssuedKeys::<T>::get(&origin_address, &fingerprint), Some(location));
@@ -80,6 +141,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn announce_key_with_long_vectors() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let origin = get_origin::<T>("Anakin");
 		let location =
 			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
@@ -87,7 +149,14 @@ mod benchmarks {
 			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
 
 		#[extrinsic_call]
-		announce_key(origin.clone(), fingerprint.clone(), location.clone());
+		announce_key(
+			origin.clone(),
+			fingerprint.clone(),
+			location.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None,
+		);
 
 		let origin_address = get_account::<T>("Anakin");
 		assert_eq!(IssuedKeys::<T>::get(&origin_address, &fingerprint), Some(location));
@@ -97,6 +166,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn announce_a_bunch_of_long_keys() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		// Check how the extrinsic performs with a lot of keys in storage already.
 		for i in 0..100_000 {
 			let origin = get_origin::<T>("Anakin");
@@ -107,7 +177,14 @@ mod benchmarks {
 			let location =
 				BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
 
-			Keystore::<T>::announce_key(origin.into(), fingerprint, location)?;
+			Keystore::<T>::announce_key(
+				origin.into(),
+				fingerprint,
+				location,
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				None,
+			)?;
 		}
 
 		let origin = get_origin::<T>("Anakin");
@@ -117,7 +194,14 @@ mod benchmarks {
 			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
 
 		#[extrinsic_call]
-		announce_key(origin.clone(), fingerprint.clone(), location.clone());
+		announce_key(
+			origin.clone(),
+			fingerprint.clone(),
+			location.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None,
+		);
 
 		let origin_address = get_account::<T>("Anakin");
 		assert_eq!(IssuedKeys::<T>::get(&origin_address, &fingerprint), Some(location));
@@ -127,6 +211,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn revoke_key() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let origin = get_origin::<T>("Anakin");
 		let key_index = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(
 			"somekey".as_bytes().to_vec(),
@@ -137,6 +222,9 @@ mod benchmarks {
 			origin.clone().into(),
 			key_index.clone(),
 			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 32]).unwrap(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None,
 		)?;
 
 		#[extrinsic_call]
@@ -150,6 +238,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn revoke_one_of_many_keys() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
 		let origin = get_origin::<T>("Anakin");
 
 		// Check how the extrinsic performs with a lot of keys in storage already.
@@ -162,6 +251,9 @@ mod benchmarks {
 				)
 				.unwrap(),
 				BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 32]).unwrap(),
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				None,
 			)?;
 		}
 
@@ -173,6 +265,9 @@ mod benchmarks {
 			origin.clone().into(),
 			key_index.clone(),
 			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 32]).unwrap(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None,
 		)?;
 
 		#[extrinsic_call]
@@ -202,7 +297,7 @@ mod benchmarks {
 	fn issue_a_ton_of_encryption_keys() -> Result<(), BenchmarkError> {
 		// Set up a load of encryption keys in storage
 		// so we can see what impact it has on runtime.
-		for _ in 0..100_000 {
+		for _ in 0..T::MaxEncryptionKeys::get() - 1 {
 			let origin = get_origin::<T>("Anakin");
 			let key = [0; 32];
 
@@ -221,5 +316,402 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn rotate_key() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		let origin = get_origin::<T>("Anakin");
+		let old_fingerprint =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+		let new_fingerprint =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![1; 1000]).unwrap();
+		let location =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+
+		Keystore::<T>::announce_key(
+			origin.clone().into(),
+			old_fingerprint.clone(),
+			location.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None,
+		)?;
+
+		#[extrinsic_call]
+		_(origin.clone(), old_fingerprint.clone(), new_fingerprint.clone(), location.clone());
+
+		let origin_address = get_account::<T>("Anakin");
+		assert_eq!(IssuedKeys::<T>::get(&origin_address, &old_fingerprint), None);
+		assert_eq!(IssuedKeys::<T>::get(&origin_address, &new_fingerprint), Some(location));
+		assert_eq!(
+			KeyPredecessor::<T>::get(&origin_address, &new_fingerprint),
+			Some(old_fingerprint)
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_keys(
+		n: Linear<0, { T::MaxExpiringKeysPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		let origin = get_origin::<T>("Anakin");
+		let location =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+		let expires_at: BlockNumberFor<T> = 2u32.into();
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		for i in 0..n {
+			let fingerprint = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(
+				format!("fingerprint{}", i).as_bytes().to_vec(),
+			)
+			.unwrap();
+			Keystore::<T>::announce_key(
+				origin.clone().into(),
+				fingerprint,
+				location.clone(),
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				Some(expires_at),
+			)?;
+		}
+
+		#[block]
+		{
+			Keystore::<T>::on_initialize(expires_at);
+		}
+
+		let origin_address = get_account::<T>("Anakin");
+		assert_eq!(IssuedKeys::<T>::iter_prefix(&origin_address).count(), 0);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_encryption_key() -> Result<(), BenchmarkError> {
+		let origin = get_origin::<T>("Anakin");
+		let key = [0; 32];
+
+		Keystore::<T>::issue_encryption_key(origin.clone().into(), key)?;
+
+		#[extrinsic_call]
+		_(origin.clone(), 0);
+
+		let origin_address = get_account::<T>("Anakin");
+		assert_eq!(IssuedEncryptionKeys::<T>::get(&origin_address), None);
+		assert!(EncryptionKeyVersions::<T>::get(&origin_address, 0).unwrap().revoked);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn announce_key_with_proof() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		let origin = get_origin::<T>("Anakin");
+		let origin_address = get_account::<T>("Anakin");
+		let location =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+		let expires_at: BlockNumberFor<T> = 10u32.into();
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		let public = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+		let payload = Keystore::<T>::key_ownership_payload(&origin_address);
+		let signature = sp_io::crypto::sr25519_sign(KEY_TYPE, &public, &payload).unwrap();
+
+		#[extrinsic_call]
+		_(
+			origin.clone(),
+			MultiSigner::Sr25519(public),
+			MultiSignature::Sr25519(signature),
+			location.clone(),
+			KeyPurpose::Signing,
+			Some(expires_at),
+		);
+
+		let fingerprint =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(public.0.to_vec()).unwrap();
+		assert_eq!(IssuedKeys::<T>::get(&origin_address, &fingerprint), Some(location));
+		assert_eq!(ProvenKeys::<T>::get(&origin_address, &fingerprint), Some(()));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_key_delegate() -> Result<(), BenchmarkError> {
+		let origin = get_origin::<T>("Anakin");
+		let delegate = get_account::<T>("Obi-Wan");
+		let permissions = DelegatePermissions { announce: true, revoke: true };
+		for i in 0..T::MaxKeyDelegates::get() - 1 {
+			Keystore::<T>::add_key_delegate(
+				origin.clone().into(),
+				benchmark_account("delegate", i, 0),
+				permissions,
+			)?;
+		}
+
+		#[extrinsic_call]
+		_(origin.clone(), delegate.clone(), permissions);
+
+		let origin_address = get_account::<T>("Anakin");
+		assert_eq!(KeyDelegates::<T>::get(&origin_address, &delegate), Some(permissions));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_key_delegate() -> Result<(), BenchmarkError> {
+		let origin = get_origin::<T>("Anakin");
+		let delegate = get_account::<T>("Obi-Wan");
+
+		Keystore::<T>::add_key_delegate(
+			origin.clone().into(),
+			delegate.clone(),
+			DelegatePermissions { announce: true, revoke: true },
+		)?;
+
+		#[extrinsic_call]
+		_(origin.clone(), delegate.clone());
+
+		let origin_address = get_account::<T>("Anakin");
+		assert_eq!(KeyDelegates::<T>::get(&origin_address, &delegate), None);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn announce_key_for() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		let origin = get_origin::<T>("Anakin");
+		let origin_address = get_account::<T>("Anakin");
+		let delegate = get_origin::<T>("Obi-Wan");
+		let location =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+		let fingerprint =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+		let expires_at: BlockNumberFor<T> = 10u32.into();
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		Keystore::<T>::add_key_delegate(
+			origin.into(),
+			get_account::<T>("Obi-Wan"),
+			DelegatePermissions { announce: true, revoke: false },
+		)?;
+
+		#[extrinsic_call]
+		_(
+			delegate,
+			origin_address.clone(),
+			fingerprint.clone(),
+			location.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			Some(expires_at),
+		);
+
+		assert_eq!(IssuedKeys::<T>::get(&origin_address, &fingerprint), Some(location));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_key_for() -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		let origin = get_origin::<T>("Anakin");
+		let origin_address = get_account::<T>("Anakin");
+		let delegate = get_origin::<T>("Obi-Wan");
+		let fingerprint =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+
+		Keystore::<T>::announce_key(
+			origin.clone().into(),
+			fingerprint.clone(),
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None,
+		)?;
+		Keystore::<T>::add_key_delegate(
+			origin.into(),
+			get_account::<T>("Obi-Wan"),
+			DelegatePermissions { announce: false, revoke: true },
+		)?;
+
+		#[extrinsic_call]
+		_(delegate, origin_address.clone(), fingerprint.clone());
+
+		assert_eq!(IssuedKeys::<T>::get(&origin_address, &fingerprint), None);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_guardians() -> Result<(), BenchmarkError> {
+		let origin = get_origin::<T>("Anakin");
+		let guardians: Vec<T::AccountId> = (0..T::MaxGuardians::get())
+			.map(|i| benchmark_account("guardian", i, 0))
+			.collect();
+		let guardians = BoundedVec::try_from(guardians).unwrap();
+
+		#[extrinsic_call]
+		_(origin.clone(), guardians, T::MaxGuardians::get());
+
+		let origin_address = get_account::<T>("Anakin");
+		assert!(Guardians::<T>::contains_key(&origin_address));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_guardians() -> Result<(), BenchmarkError> {
+		let origin = get_origin::<T>("Anakin");
+		set_max_guardians::<T>("Anakin");
+
+		#[extrinsic_call]
+		_(origin.clone());
+
+		let origin_address = get_account::<T>("Anakin");
+		assert!(!Guardians::<T>::contains_key(&origin_address));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn initiate_recovery() -> Result<(), BenchmarkError> {
+		let origin_address = get_account::<T>("Anakin");
+		let guardians = set_max_guardians::<T>("Anakin");
+		let fingerprint =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+		let location =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(guardians[0].clone()),
+			origin_address.clone(),
+			fingerprint,
+			location,
+			KeyAlgorithm::Ed25519,
+		);
+
+		assert!(ActiveRecoveries::<T>::contains_key(&origin_address));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_recovery() -> Result<(), BenchmarkError> {
+		let origin_address = get_account::<T>("Anakin");
+		let guardians = set_max_guardians::<T>("Anakin");
+		let fingerprint =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+		let location =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+
+		Keystore::<T>::initiate_recovery(
+			RawOrigin::Signed(guardians[0].clone()).into(),
+			origin_address.clone(),
+			fingerprint,
+			location,
+			KeyAlgorithm::Ed25519,
+		)?;
+		// Fill the approvals so the membership check covers as many guardians as possible.
+		let last = guardians.len() - 1;
+		for guardian in guardians.iter().take(last).skip(1) {
+			Keystore::<T>::approve_recovery(
+				RawOrigin::Signed(guardian.clone()).into(),
+				origin_address.clone(),
+			)?;
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(guardians[last].clone()), origin_address.clone());
+
+		assert_eq!(
+			ActiveRecoveries::<T>::get(&origin_address).unwrap().approvals.len(),
+			guardians.len()
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_recovery() -> Result<(), BenchmarkError> {
+		let origin = get_origin::<T>("Anakin");
+		let origin_address = get_account::<T>("Anakin");
+		let guardians = set_max_guardians::<T>("Anakin");
+		let fingerprint =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+		let location =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+
+		Keystore::<T>::initiate_recovery(
+			RawOrigin::Signed(guardians[0].clone()).into(),
+			origin_address.clone(),
+			fingerprint,
+			location,
+			KeyAlgorithm::Ed25519,
+		)?;
+
+		#[extrinsic_call]
+		_(origin.clone());
+
+		assert!(!ActiveRecoveries::<T>::contains_key(&origin_address));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn complete_recovery(n: Linear<0, 1000>) -> Result<(), BenchmarkError> {
+		fund_account::<T>("Anakin");
+		let origin = get_origin::<T>("Anakin");
+		let origin_address = get_account::<T>("Anakin");
+		let location =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		for i in 0..n {
+			let fingerprint = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(
+				format!("fingerprint{}", i).as_bytes().to_vec(),
+			)
+			.unwrap();
+			Keystore::<T>::announce_key(
+				origin.clone().into(),
+				fingerprint,
+				location.clone(),
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				None,
+			)?;
+		}
+
+		let guardians = set_max_guardians::<T>("Anakin");
+		let recovery_fingerprint =
+			BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(vec![0; 1000]).unwrap();
+		Keystore::<T>::initiate_recovery(
+			RawOrigin::Signed(guardians[0].clone()).into(),
+			origin_address.clone(),
+			recovery_fingerprint.clone(),
+			location.clone(),
+			KeyAlgorithm::Ed25519,
+		)?;
+		for guardian in &guardians[1..] {
+			Keystore::<T>::approve_recovery(
+				RawOrigin::Signed(guardian.clone()).into(),
+				origin_address.clone(),
+			)?;
+		}
+		frame_system::Pallet::<T>::set_block_number(
+			T::RecoveryDelay::get().saturating_add(1u32.into()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(guardians[0].clone()), origin_address.clone(), n);
+
+		assert_eq!(IssuedKeys::<T>::iter_prefix(&origin_address).count(), 1);
+		assert_eq!(IssuedKeys::<T>::get(&origin_address, &recovery_fingerprint), Some(location));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Keystore, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 #[cfg(test)]
 mod mock;
 
@@ -9,6 +11,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
 pub use pallet::*;
@@ -16,11 +19,26 @@ pub use weights::*;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+	use alloc::vec::Vec;
+	use frame_support::{
+		dispatch::DispatchResult,
+		pallet_prelude::*,
+		traits::{Currency, ReservableCurrency},
+	};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{Saturating, Verify, Zero},
+		MultiSignature, MultiSigner,
+	};
 
 	use crate::weights::WeightInfo;
 
+	type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Domain separator for the payload signed when proving ownership of a key.
+	const KEY_OWNERSHIP_CONTEXT: &[u8] = b"fennel/keystore/ownership";
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// The overarching event type.
@@ -29,9 +47,155 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		/// The maximum size of a key.
 		type MaxSize: Get<u32>;
+		/// The maximum number of key rotations remembered for each account.
+		type MaxKeyHistory: Get<u32>;
+		/// The maximum number of keys that may be scheduled to expire in a single block.
+		type MaxExpiringKeysPerBlock: Get<u32>;
+		/// The maximum number of encryption keys an account may issue, counting revoked ones.
+		type MaxEncryptionKeys: Get<u32>;
+		/// The maximum number of delegates an account may appoint to manage its keys.
+		type MaxKeyDelegates: Get<u32>;
+		/// The currency in which storage deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The deposit reserved for each byte of fingerprint and location an announced key stores.
+		type DepositPerByte: Get<BalanceOf<Self>>;
+		/// The maximum number of guardians an account may appoint for key recovery.
+		type MaxGuardians: Get<u32>;
+		/// How many blocks must pass between a recovery being initiated and it being completed,
+		/// giving the owner time to cancel it.
+		type RecoveryDelay: Get<BlockNumberFor<Self>>;
+	}
+
+	/// A record of one announced key being superseded by another.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct KeyRotation<Fingerprint, BlockNumber> {
+		/// The fingerprint of the key that was retired.
+		pub old_fingerprint: Fingerprint,
+		/// The fingerprint of the key that replaced it.
+		pub new_fingerprint: Fingerprint,
+		/// The block in which the rotation took place.
+		pub rotated_at: BlockNumber,
 	}
 
+	pub type KeyRotationOf<T> =
+		KeyRotation<BoundedVec<u8, <T as Config>::MaxSize>, BlockNumberFor<T>>;
+
+	/// What an announced key is meant to be used for.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum KeyPurpose {
+		/// Producing and checking signatures.
+		Signing,
+		/// Encrypting data for the key owner.
+		Encryption,
+		/// Proving control of the owning account to a service.
+		Authentication,
+		/// Regaining control after other keys have been lost.
+		Recovery,
+	}
+
+	/// The algorithm an announced key belongs to.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum KeyAlgorithm {
+		/// An RSA key, typically published as an OpenPGP certificate.
+		Rsa,
+		/// An Ed25519 key.
+		Ed25519,
+		/// A Schnorrkel/Ristretto sr25519 key.
+		Sr25519,
+		/// An ECDSA key over secp256k1.
+		Ecdsa,
+		/// An X25519 Diffie-Hellman key.
+		X25519,
+	}
+
+	/// One version of an account's encryption key.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct EncryptionKeyRecord<BlockNumber> {
+		/// The 32-byte public key.
+		pub key: [u8; 32],
+		/// The block in which the key was issued.
+		pub created_at: BlockNumber,
+		/// Whether the owner has revoked the key.
+		pub revoked: bool,
+	}
+
+	/// Everything recorded about a single announced key, as returned by the runtime API.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct KeyDetails<BlockNumber> {
+		/// The fingerprint the key was announced under.
+		pub fingerprint: Vec<u8>,
+		/// Where the key was published.
+		pub location: Vec<u8>,
+		/// The purpose and algorithm declared for the key, if any.
+		pub metadata: Option<KeyMetadata>,
+		/// The block at which the key will be revoked automatically, if any.
+		pub expires_at: Option<BlockNumber>,
+		/// Whether the announcer proved control of the key.
+		pub proven: bool,
+	}
+
+	/// Describes how an announced key should be used.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct KeyMetadata {
+		/// What the key is meant to be used for.
+		pub purpose: KeyPurpose,
+		/// The algorithm the key belongs to.
+		pub algorithm: KeyAlgorithm,
+	}
+
+	/// What a delegate may do with the keys of the account that appointed it.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct DelegatePermissions {
+		/// Whether the delegate may announce keys on the owner's behalf.
+		pub announce: bool,
+		/// Whether the delegate may revoke the owner's keys.
+		pub revoke: bool,
+	}
+
+	/// The accounts allowed to recover an owner's keys and how many of them must agree.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct GuardianSet<Guardians> {
+		/// The appointed guardians.
+		pub guardians: Guardians,
+		/// The number of guardians that must approve a recovery.
+		pub threshold: u32,
+	}
+
+	pub type GuardianSetOf<T> = GuardianSet<
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxGuardians>,
+	>;
+
+	/// A recovery in progress, replacing all of an owner's keys with a single recovery key.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RecoveryRequest<Fingerprint, Guardians, BlockNumber> {
+		/// The fingerprint of the key to install once the recovery completes.
+		pub fingerprint: Fingerprint,
+		/// Where the recovery key was published.
+		pub location: Fingerprint,
+		/// The algorithm the recovery key belongs to.
+		pub algorithm: KeyAlgorithm,
+		/// The guardians that have approved the recovery so far.
+		pub approvals: Guardians,
+		/// The block in which the recovery was initiated.
+		pub initiated_at: BlockNumber,
+	}
+
+	pub type RecoveryRequestOf<T> = RecoveryRequest<
+		BoundedVec<u8, <T as Config>::MaxSize>,
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxGuardians>,
+		BlockNumberFor<T>,
+	>;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -47,21 +211,197 @@ pub mod pallet {
 		BoundedVec<u8, T::MaxSize>,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn key_metadata)]
+	/// The purpose and algorithm declared for each announced key.
+	pub type IssuedKeyMetadata<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		KeyMetadata,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn proven_key)]
+	/// Keys whose announcer proved control of the private key when announcing them.
+	pub type ProvenKeys<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_deposit)]
+	/// The deposit reserved from the owner of each announced key, returned when the key is removed.
+	pub type KeyDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		BalanceOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_delegate)]
+	/// Accounts an owner has allowed to manage its keys, and what each of them may do.
+	pub type KeyDelegates<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		DelegatePermissions,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_delegate_count)]
+	/// The number of delegates each account has appointed.
+	pub type KeyDelegateCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn guardians)]
+	/// The guardians each account has appointed to recover its keys.
+	pub type Guardians<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, GuardianSetOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn active_recovery)]
+	/// Recoveries that guardians have initiated and that have not yet completed or been cancelled.
+	pub type ActiveRecoveries<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, RecoveryRequestOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_proof_nonce)]
+	/// The nonce each account must include in its next proof of key ownership.
+	pub type KeyProofNonce<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_predecessor)]
+	/// Points a key that was introduced through a rotation back to the key it superseded.
+	pub type KeyPredecessor<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		BoundedVec<u8, T::MaxSize>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_history)]
+	/// The most recent key rotations performed by each account, oldest first.
+	pub type KeyHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<KeyRotationOf<T>, T::MaxKeyHistory>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_expiry)]
+	/// The block at which an announced key stops being valid, if the owner set one.
+	pub type KeyExpiry<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSize>,
+		BlockNumberFor<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_keys)]
+	/// Keys scheduled to be revoked automatically at the start of a given block.
+	pub type ExpiringKeys<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::AccountId, BoundedVec<u8, T::MaxSize>), T::MaxExpiringKeysPerBlock>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn encryption_key)]
-	/// Maps an account to an encryption key that they've issued.
+	/// Maps an account to the encryption key that should currently be used to reach them.
 	pub type IssuedEncryptionKeys<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, [u8; 32]>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn current_encryption_key_id)]
+	/// The ID of the encryption key held in `IssuedEncryptionKeys` for each account.
+	pub type CurrentEncryptionKeyId<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_encryption_key_id)]
+	/// The ID that will be given to the next encryption key issued by each account.
+	pub type NextEncryptionKeyId<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn encryption_key_version)]
+	/// Every encryption key an account has issued, including revoked ones, so that older
+	/// ciphertexts can still be matched to the key they were encrypted for.
+	pub type EncryptionKeyVersions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		EncryptionKeyRecord<BlockNumberFor<T>>,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// Announce when an identity has broadcast a new key as an event.
-		EncryptionKeyIssued(T::AccountId),
+		/// Announce when an identity has broadcast a new encryption key. Contains the owning
+		/// AccountId and the ID of the key.
+		EncryptionKeyIssued(T::AccountId, u32),
+		/// Announce that an encryption key has been revoked. Contains the owning AccountId and the
+		/// ID of the key.
+		EncryptionKeyRevoked(T::AccountId, u32),
 		/// Announce when an identity has set a key as revoked.
 		KeyRevoked(BoundedVec<u8, T::MaxSize>, T::AccountId),
 		/// Announce that a key exists.
 		KeyAnnounced(BoundedVec<u8, T::MaxSize>, T::AccountId),
+		/// Announce that a key has been replaced. Contains the old fingerprint, the new
+		/// fingerprint and the owning AccountId.
+		KeyRotated(BoundedVec<u8, T::MaxSize>, BoundedVec<u8, T::MaxSize>, T::AccountId),
+		/// Announce that a key reached its expiry block and was revoked automatically.
+		KeyExpired(BoundedVec<u8, T::MaxSize>, T::AccountId),
+		/// Announce that an account appointed or updated a delegate. Contains the owner, the
+		/// delegate and what the delegate may do.
+		KeyDelegateAdded(T::AccountId, T::AccountId, DelegatePermissions),
+		/// Announce that an account dismissed a delegate. Contains the owner and the delegate.
+		KeyDelegateRemoved(T::AccountId, T::AccountId),
+		/// Announce that a delegate announced a key. Contains the fingerprint, the owner and the
+		/// delegate that acted.
+		KeyAnnouncedByDelegate(BoundedVec<u8, T::MaxSize>, T::AccountId, T::AccountId),
+		/// Announce that a delegate revoked a key. Contains the fingerprint, the owner and the
+		/// delegate that acted.
+		KeyRevokedByDelegate(BoundedVec<u8, T::MaxSize>, T::AccountId, T::AccountId),
+		/// Announce that an account appointed guardians. Contains the owner and the number of
+		/// approvals a recovery needs.
+		GuardiansSet(T::AccountId, u32),
+		/// Announce that an account dismissed its guardians.
+		GuardiansCleared(T::AccountId),
+		/// Announce that a guardian started recovering an account's keys. Contains the owner and
+		/// the guardian.
+		RecoveryInitiated(T::AccountId, T::AccountId),
+		/// Announce that a guardian approved a recovery. Contains the owner and the guardian.
+		RecoveryApproved(T::AccountId, T::AccountId),
+		/// Announce that the owner cancelled a recovery of its keys.
+		RecoveryCancelled(T::AccountId),
+		/// Announce that a recovery completed and a recovery key replaced the owner's keys.
+		/// Contains the fingerprint of the recovery key and the owner.
+		KeyRecovered(BoundedVec<u8, T::MaxSize>, T::AccountId),
 	}
 
 	#[pallet::error]
@@ -70,23 +410,269 @@ pub mod pallet {
 		KeyExists,
 		/// The specified key does not exist.
 		KeyDoesNotExist,
+		/// The requested expiry block is not in the future.
+		ExpiryInPast,
+		/// Too many keys are already scheduled to expire in the requested block.
+		TooManyExpiringKeys,
+		/// The specified encryption key does not exist.
+		EncryptionKeyDoesNotExist,
+		/// The specified encryption key has already been revoked.
+		EncryptionKeyAlreadyRevoked,
+		/// An encryption key ID could not be incremented without overflowing.
+		StorageOverflow,
+		/// The account has already issued `MaxEncryptionKeys` encryption keys.
+		TooManyEncryptionKeys,
+		/// The signature does not prove ownership of the submitted public key.
+		InvalidKeyProof,
+		/// The submitted public key does not fit in a fingerprint.
+		FingerprintTooLong,
+		/// The account cannot afford the deposit for storing the key.
+		InsufficientBalance,
+		/// The caller is not a delegate of the key owner.
+		NotKeyDelegate,
+		/// The account has already appointed `MaxKeyDelegates` delegates.
+		TooManyKeyDelegates,
+		/// The delegate is not permitted to perform this action for the key owner.
+		DelegatePermissionDenied,
+		/// The approval threshold must be at least one and no more than the number of guardians.
+		InvalidGuardianThreshold,
+		/// The same account was listed as a guardian more than once, or the owner listed itself.
+		InvalidGuardian,
+		/// The account has not appointed any guardians.
+		NoGuardians,
+		/// The caller is not a guardian of the account.
+		NotGuardian,
+		/// A recovery of the account's keys is already in progress.
+		RecoveryAlreadyActive,
+		/// No recovery of the account's keys is in progress.
+		NoActiveRecovery,
+		/// The guardian has already approved this recovery.
+		AlreadyApproved,
+		/// Not enough guardians have approved the recovery yet.
+		RecoveryThresholdNotMet,
+		/// The recovery delay has not yet passed.
+		RecoveryDelayNotElapsed,
+		/// The account holds more keys than the caller allowed for.
+		KeyCountWitnessTooLow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Revokes every key whose expiry falls on this block.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expiring = <ExpiringKeys<T>>::take(now);
+			let count = expiring.len() as u32;
+
+			for (who, fingerprint) in expiring {
+				// Keys that were revoked, rotated or re-announced since being scheduled no longer
+				// carry this expiry and are left alone.
+				if <KeyExpiry<T>>::get(&who, &fingerprint) == Some(now) {
+					Self::remove_key(&who, &fingerprint);
+					Self::deposit_event(Event::KeyExpired(fingerprint, who));
+				}
+			}
+
+			T::WeightInfo::expire_keys(count)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Records that `fingerprint` should be revoked automatically at block `expires_at`.
+		fn schedule_expiry(
+			who: &T::AccountId,
+			fingerprint: &BoundedVec<u8, T::MaxSize>,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure!(
+				expires_at > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::ExpiryInPast
+			);
+
+			<ExpiringKeys<T>>::try_mutate(expires_at, |expiring| {
+				expiring
+					.try_push((who.clone(), fingerprint.clone()))
+					.map_err(|_| Error::<T>::TooManyExpiringKeys)
+			})?;
+			<KeyExpiry<T>>::insert(who, fingerprint, expires_at);
+
+			Ok(())
+		}
+
+		/// Stores a newly announced key along with its metadata and optional expiry.
+		fn insert_key(
+			who: &T::AccountId,
+			fingerprint: &BoundedVec<u8, T::MaxSize>,
+			location: &BoundedVec<u8, T::MaxSize>,
+			metadata: KeyMetadata,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure!(!<IssuedKeys<T>>::contains_key(who, fingerprint), Error::<T>::KeyExists);
+
+			let deposit = Self::deposit_for(fingerprint, location);
+			T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+			<KeyDeposits<T>>::insert(who, fingerprint, deposit);
+
+			if let Some(expires_at) = expires_at {
+				Self::schedule_expiry(who, fingerprint, expires_at)?;
+			}
+			<IssuedKeys<T>>::insert(who, fingerprint, location);
+			<IssuedKeyMetadata<T>>::insert(who, fingerprint, metadata);
+
+			Ok(())
+		}
+
+		/// Deletes an announced key along with any expiry, metadata, proof and predecessor link
+		/// attached to it, and returns its deposit to the owner.
+		fn remove_key(who: &T::AccountId, fingerprint: &BoundedVec<u8, T::MaxSize>) {
+			if let Some(deposit) = <KeyDeposits<T>>::take(who, fingerprint) {
+				T::Currency::unreserve(who, deposit);
+			}
+			<IssuedKeys<T>>::remove(who, fingerprint);
+			<IssuedKeyMetadata<T>>::remove(who, fingerprint);
+			if let Some(expires_at) = <KeyExpiry<T>>::take(who, fingerprint) {
+				// Free the key's slot in its expiry block. When the key is removed by its own
+				// expiry, that block has already been taken and there is nothing left to write.
+				let _ = <ExpiringKeys<T>>::try_mutate_exists(
+					expires_at,
+					|maybe_expiring| -> Result<(), ()> {
+						let expiring = maybe_expiring.as_mut().ok_or(())?;
+						expiring.retain(|(owner, key)| !(owner == who && key == fingerprint));
+						if expiring.is_empty() {
+							*maybe_expiring = None;
+						}
+						Ok(())
+					},
+				);
+			}
+			<ProvenKeys<T>>::remove(who, fingerprint);
+			<KeyPredecessor<T>>::remove(who, fingerprint);
+		}
+
+		/// Returns what `delegate` may do with the keys of `owner`, failing if it was never
+		/// appointed.
+		fn delegate_permissions(
+			owner: &T::AccountId,
+			delegate: &T::AccountId,
+		) -> Result<DelegatePermissions, DispatchError> {
+			<KeyDelegates<T>>::get(owner, delegate).ok_or_else(|| Error::<T>::NotKeyDelegate.into())
+		}
+
+		/// Returns the guardians of `owner`, failing unless `guardian` is one of them.
+		fn ensure_guardian(
+			owner: &T::AccountId,
+			guardian: &T::AccountId,
+		) -> Result<GuardianSetOf<T>, DispatchError> {
+			let set = <Guardians<T>>::get(owner).ok_or(Error::<T>::NoGuardians)?;
+			ensure!(set.guardians.contains(guardian), Error::<T>::NotGuardian);
+			Ok(set)
+		}
+
+		/// The deposit required to store a key with the given fingerprint and location.
+		pub fn deposit_for(
+			fingerprint: &BoundedVec<u8, T::MaxSize>,
+			location: &BoundedVec<u8, T::MaxSize>,
+		) -> BalanceOf<T> {
+			let bytes = fingerprint.len().saturating_add(location.len()) as u32;
+			T::DepositPerByte::get().saturating_mul(bytes.into())
+		}
+
+		/// The payload `who` must sign with a key to prove they control it. It binds the account,
+		/// the genesis hash of this chain and the account's current proof nonce, so a proof
+		/// cannot be replayed by another account, on another chain or a second time.
+		pub fn key_ownership_payload(who: &T::AccountId) -> Vec<u8> {
+			let genesis_hash = <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero());
+			(KEY_OWNERSHIP_CONTEXT, who, genesis_hash, <KeyProofNonce<T>>::get(who)).encode()
+		}
+
+		/// Lists every key currently announced by `who`.
+		pub fn keys_of(who: &T::AccountId) -> Vec<KeyDetails<BlockNumberFor<T>>> {
+			<IssuedKeys<T>>::iter_prefix(who)
+				.map(|(fingerprint, location)| KeyDetails {
+					metadata: <IssuedKeyMetadata<T>>::get(who, &fingerprint),
+					expires_at: <KeyExpiry<T>>::get(who, &fingerprint),
+					proven: <ProvenKeys<T>>::contains_key(who, &fingerprint),
+					fingerprint: fingerprint.into_inner(),
+					location: location.into_inner(),
+				})
+				.collect()
+		}
+
+		/// Returns the encryption key `who` currently wants to be reached with, and its ID.
+		pub fn current_encryption_key(
+			who: &T::AccountId,
+		) -> Option<(u32, EncryptionKeyRecord<BlockNumberFor<T>>)> {
+			let key_id = <CurrentEncryptionKeyId<T>>::get(who)?;
+			<EncryptionKeyVersions<T>>::get(who, key_id).map(|record| (key_id, record))
+		}
+
+		/// Whether `fingerprint` is currently announced by `who` and has not yet expired.
+		pub fn is_key_valid(who: &T::AccountId, fingerprint: &[u8]) -> bool {
+			let Ok(fingerprint) = BoundedVec::<u8, T::MaxSize>::try_from(fingerprint.to_vec())
+			else {
+				return false;
+			};
+			if !<IssuedKeys<T>>::contains_key(who, &fingerprint) {
+				return false;
+			}
+			match <KeyExpiry<T>>::get(who, &fingerprint) {
+				Some(expires_at) => expires_at > <frame_system::Pallet<T>>::block_number(),
+				None => true,
+			}
+		}
+
+		/// Checks that `signature` was produced over `payload` by the private half of `public`.
+		fn verify_key_ownership(
+			public: &MultiSigner,
+			signature: &MultiSignature,
+			payload: &[u8],
+		) -> bool {
+			match (public, signature) {
+				(MultiSigner::Ed25519(public), MultiSignature::Ed25519(signature)) =>
+					signature.verify(payload, public),
+				(MultiSigner::Sr25519(public), MultiSignature::Sr25519(signature)) =>
+					signature.verify(payload, public),
+				(MultiSigner::Ecdsa(public), MultiSignature::Ecdsa(signature)) =>
+					signature.verify(payload, public),
+				_ => false,
+			}
+		}
+
+		/// Lists the fingerprints of every key `who` has announced for `purpose`.
+		pub fn keys_by_purpose(
+			who: &T::AccountId,
+			purpose: KeyPurpose,
+		) -> Vec<BoundedVec<u8, T::MaxSize>> {
+			<IssuedKeyMetadata<T>>::iter_prefix(who)
+				.filter(|(_, metadata)| metadata.purpose == purpose)
+				.map(|(fingerprint, _)| fingerprint)
+				.collect()
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		// Can we add documentation here?
+		/// Announces a key by its fingerprint and the location it was published to, along with
+		/// what the key is for and which algorithm it uses. If `expires_at` is given, the key is
+		/// revoked automatically once that block is reached.
 		#[pallet::weight(T::WeightInfo::announce_key())]
 		#[pallet::call_index(0)]
 		pub fn announce_key(
 			origin: OriginFor<T>,
 			fingerprint: BoundedVec<u8, T::MaxSize>,
 			location: BoundedVec<u8, T::MaxSize>,
+			purpose: KeyPurpose,
+			algorithm: KeyAlgorithm,
+			expires_at: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(!<IssuedKeys<T>>::contains_key(&who, &fingerprint), Error::<T>::KeyExists);
-
-			<IssuedKeys<T>>::insert(&who, &fingerprint, &location);
+			Self::insert_key(
+				&who,
+				&fingerprint,
+				&location,
+				KeyMetadata { purpose, algorithm },
+				expires_at,
+			)?;
 
 			Self::deposit_event(Event::KeyAnnounced(fingerprint, who));
 			Ok(())
@@ -104,21 +690,408 @@ pub mod pallet {
 
 			ensure!(<IssuedKeys<T>>::contains_key(&who, &key_index), Error::<T>::KeyDoesNotExist);
 
-			<IssuedKeys<T>>::remove(&who, &key_index);
+			Self::remove_key(&who, &key_index);
 
 			Self::deposit_event(Event::KeyRevoked(key_index, who));
 			Ok(())
 		}
 
-		/// Announces an encryption key to the network.
+		/// Announces an encryption key to the network. The key becomes the account's current
+		/// encryption key, while earlier keys remain resolvable by their ID. Every version is
+		/// kept, so an account can issue at most `MaxEncryptionKeys` of them.
 		#[pallet::weight(T::WeightInfo::issue_encryption_key())]
 		#[pallet::call_index(2)]
 		pub fn issue_encryption_key(origin: OriginFor<T>, key: [u8; 32]) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
+			let key_id = <NextEncryptionKeyId<T>>::get(&who);
+			ensure!(key_id < T::MaxEncryptionKeys::get(), Error::<T>::TooManyEncryptionKeys);
+			let next_id = key_id.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+
+			<EncryptionKeyVersions<T>>::insert(
+				&who,
+				key_id,
+				EncryptionKeyRecord {
+					key,
+					created_at: <frame_system::Pallet<T>>::block_number(),
+					revoked: false,
+				},
+			);
+			<NextEncryptionKeyId<T>>::insert(&who, next_id);
+			<CurrentEncryptionKeyId<T>>::insert(&who, key_id);
 			<IssuedEncryptionKeys<T>>::insert(&who, key);
 
-			Self::deposit_event(Event::EncryptionKeyIssued(who));
+			Self::deposit_event(Event::EncryptionKeyIssued(who, key_id));
+			Ok(())
+		}
+
+		/// Replaces `old_fingerprint` with `new_fingerprint` in a single step. The new key keeps a
+		/// pointer to the key it supersedes and the rotation is appended to the account's key
+		/// history, dropping the oldest entry once the history is full. The new key inherits the
+		/// old key's purpose and algorithm, but any expiry set on the old key is not carried over:
+		/// the old key's scheduled expiry is cancelled and the new key never expires.
+		#[pallet::weight(T::WeightInfo::rotate_key())]
+		#[pallet::call_index(3)]
+		pub fn rotate_key(
+			origin: OriginFor<T>,
+			old_fingerprint: BoundedVec<u8, T::MaxSize>,
+			new_fingerprint: BoundedVec<u8, T::MaxSize>,
+			new_location: BoundedVec<u8, T::MaxSize>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				<IssuedKeys<T>>::contains_key(&who, &old_fingerprint),
+				Error::<T>::KeyDoesNotExist
+			);
+			ensure!(!<IssuedKeys<T>>::contains_key(&who, &new_fingerprint), Error::<T>::KeyExists);
+
+			// Reserve for the new key before the old deposit is returned, so a rotation the
+			// owner cannot afford leaves the old key in place.
+			let deposit = Self::deposit_for(&new_fingerprint, &new_location);
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			let metadata = <IssuedKeyMetadata<T>>::get(&who, &old_fingerprint);
+			Self::remove_key(&who, &old_fingerprint);
+			<IssuedKeys<T>>::insert(&who, &new_fingerprint, &new_location);
+			<KeyDeposits<T>>::insert(&who, &new_fingerprint, deposit);
+			if let Some(metadata) = metadata {
+				<IssuedKeyMetadata<T>>::insert(&who, &new_fingerprint, metadata);
+			}
+			<KeyPredecessor<T>>::insert(&who, &new_fingerprint, &old_fingerprint);
+
+			let rotation = KeyRotation {
+				old_fingerprint: old_fingerprint.clone(),
+				new_fingerprint: new_fingerprint.clone(),
+				rotated_at: <frame_system::Pallet<T>>::block_number(),
+			};
+			<KeyHistory<T>>::mutate(&who, |history| {
+				if history.is_full() && !history.is_empty() {
+					history.remove(0);
+				}
+				// Only fails when the history is configured to hold nothing at all.
+				let _ = history.try_push(rotation);
+			});
+
+			Self::deposit_event(Event::KeyRotated(old_fingerprint, new_fingerprint, who));
+			Ok(())
+		}
+
+		/// Marks the encryption key with ID `key_id` as revoked. The record is kept so existing
+		/// ciphertexts can still be resolved, but if it is the account's current encryption key
+		/// the account is left without one until a new key is issued.
+		#[pallet::weight(T::WeightInfo::revoke_encryption_key())]
+		#[pallet::call_index(4)]
+		pub fn revoke_encryption_key(origin: OriginFor<T>, key_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<EncryptionKeyVersions<T>>::try_mutate(&who, key_id, |record| -> DispatchResult {
+				let record = record.as_mut().ok_or(Error::<T>::EncryptionKeyDoesNotExist)?;
+				ensure!(!record.revoked, Error::<T>::EncryptionKeyAlreadyRevoked);
+				record.revoked = true;
+				Ok(())
+			})?;
+
+			if <CurrentEncryptionKeyId<T>>::get(&who) == Some(key_id) {
+				<CurrentEncryptionKeyId<T>>::remove(&who);
+				<IssuedEncryptionKeys<T>>::remove(&who);
+			}
+
+			Self::deposit_event(Event::EncryptionKeyRevoked(who, key_id));
+			Ok(())
+		}
+
+		/// Announces a public key after checking that the caller controls it. `signature` must be
+		/// made by `public` over the payload returned by `key_ownership_payload` for the caller.
+		/// The public key itself is used as the fingerprint, and its algorithm is taken from the
+		/// key type.
+		#[pallet::weight(T::WeightInfo::announce_key_with_proof())]
+		#[pallet::call_index(5)]
+		pub fn announce_key_with_proof(
+			origin: OriginFor<T>,
+			public: MultiSigner,
+			signature: MultiSignature,
+			location: BoundedVec<u8, T::MaxSize>,
+			purpose: KeyPurpose,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let payload = Self::key_ownership_payload(&who);
+			ensure!(
+				Self::verify_key_ownership(&public, &signature, &payload),
+				Error::<T>::InvalidKeyProof
+			);
+
+			let fingerprint = BoundedVec::<u8, T::MaxSize>::try_from(public.as_ref().to_vec())
+				.map_err(|_| Error::<T>::FingerprintTooLong)?;
+			let algorithm = match public {
+				MultiSigner::Ed25519(_) => KeyAlgorithm::Ed25519,
+				MultiSigner::Sr25519(_) => KeyAlgorithm::Sr25519,
+				MultiSigner::Ecdsa(_) => KeyAlgorithm::Ecdsa,
+			};
+
+			Self::insert_key(
+				&who,
+				&fingerprint,
+				&location,
+				KeyMetadata { purpose, algorithm },
+				expires_at,
+			)?;
+			<ProvenKeys<T>>::insert(&who, &fingerprint, ());
+			<KeyProofNonce<T>>::mutate(&who, |nonce| *nonce = nonce.wrapping_add(1));
+
+			Self::deposit_event(Event::KeyAnnounced(fingerprint, who));
+			Ok(())
+		}
+
+		/// Allows `delegate` to announce and/or revoke keys on behalf of the caller. Appointing an
+		/// existing delegate again replaces its permissions. An account may appoint at most
+		/// `MaxKeyDelegates` delegates.
+		#[pallet::weight(T::WeightInfo::add_key_delegate())]
+		#[pallet::call_index(6)]
+		pub fn add_key_delegate(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+			permissions: DelegatePermissions,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if !<KeyDelegates<T>>::contains_key(&who, &delegate) {
+				<KeyDelegateCount<T>>::try_mutate(&who, |count| -> DispatchResult {
+					ensure!(*count < T::MaxKeyDelegates::get(), Error::<T>::TooManyKeyDelegates);
+					*count += 1;
+					Ok(())
+				})?;
+			}
+			<KeyDelegates<T>>::insert(&who, &delegate, permissions);
+
+			Self::deposit_event(Event::KeyDelegateAdded(who, delegate, permissions));
+			Ok(())
+		}
+
+		/// Withdraws every permission previously given to `delegate`.
+		#[pallet::weight(T::WeightInfo::remove_key_delegate())]
+		#[pallet::call_index(7)]
+		pub fn remove_key_delegate(origin: OriginFor<T>, delegate: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(<KeyDelegates<T>>::contains_key(&who, &delegate), Error::<T>::NotKeyDelegate);
+			<KeyDelegates<T>>::remove(&who, &delegate);
+			<KeyDelegateCount<T>>::mutate(&who, |count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::KeyDelegateRemoved(who, delegate));
+			Ok(())
+		}
+
+		/// Announces a key for `owner`, as `announce_key` would. The caller must be a delegate of
+		/// `owner` with permission to announce, and the deposit is reserved from `owner`.
+		#[pallet::weight(T::WeightInfo::announce_key_for())]
+		#[pallet::call_index(8)]
+		pub fn announce_key_for(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			fingerprint: BoundedVec<u8, T::MaxSize>,
+			location: BoundedVec<u8, T::MaxSize>,
+			purpose: KeyPurpose,
+			algorithm: KeyAlgorithm,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Self::delegate_permissions(&owner, &who)?.announce,
+				Error::<T>::DelegatePermissionDenied
+			);
+
+			Self::insert_key(
+				&owner,
+				&fingerprint,
+				&location,
+				KeyMetadata { purpose, algorithm },
+				expires_at,
+			)?;
+
+			Self::deposit_event(Event::KeyAnnouncedByDelegate(fingerprint, owner, who));
+			Ok(())
+		}
+
+		/// Revokes one of `owner`'s keys, as `revoke_key` would. The caller must be a delegate of
+		/// `owner` with permission to revoke, and the deposit is returned to `owner`.
+		#[pallet::weight(T::WeightInfo::revoke_key_for())]
+		#[pallet::call_index(9)]
+		pub fn revoke_key_for(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			key_index: BoundedVec<u8, T::MaxSize>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Self::delegate_permissions(&owner, &who)?.revoke,
+				Error::<T>::DelegatePermissionDenied
+			);
+			ensure!(<IssuedKeys<T>>::contains_key(&owner, &key_index), Error::<T>::KeyDoesNotExist);
+
+			Self::remove_key(&owner, &key_index);
+
+			Self::deposit_event(Event::KeyRevokedByDelegate(key_index, owner, who));
+			Ok(())
+		}
+
+		/// Appoints the accounts that may recover the caller's keys, replacing any earlier
+		/// guardians. `threshold` guardians must approve a recovery before it can complete.
+		#[pallet::weight(T::WeightInfo::set_guardians())]
+		#[pallet::call_index(10)]
+		pub fn set_guardians(
+			origin: OriginFor<T>,
+			guardians: BoundedVec<T::AccountId, T::MaxGuardians>,
+			threshold: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				threshold > 0 && threshold as usize <= guardians.len(),
+				Error::<T>::InvalidGuardianThreshold
+			);
+			for (i, guardian) in guardians.iter().enumerate() {
+				ensure!(
+					*guardian != who && !guardians[..i].contains(guardian),
+					Error::<T>::InvalidGuardian
+				);
+			}
+			ensure!(!<ActiveRecoveries<T>>::contains_key(&who), Error::<T>::RecoveryAlreadyActive);
+
+			<Guardians<T>>::insert(&who, GuardianSet { guardians, threshold });
+
+			Self::deposit_event(Event::GuardiansSet(who, threshold));
+			Ok(())
+		}
+
+		/// Dismisses all of the caller's guardians.
+		#[pallet::weight(T::WeightInfo::clear_guardians())]
+		#[pallet::call_index(11)]
+		pub fn clear_guardians(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(<Guardians<T>>::contains_key(&who), Error::<T>::NoGuardians);
+			ensure!(!<ActiveRecoveries<T>>::contains_key(&who), Error::<T>::RecoveryAlreadyActive);
+			<Guardians<T>>::remove(&who);
+
+			Self::deposit_event(Event::GuardiansCleared(who));
+			Ok(())
+		}
+
+		/// Starts recovering the keys of `owner`, proposing the key that should replace them. The
+		/// guardian starting the recovery counts as its first approval.
+		#[pallet::weight(T::WeightInfo::initiate_recovery())]
+		#[pallet::call_index(12)]
+		pub fn initiate_recovery(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			fingerprint: BoundedVec<u8, T::MaxSize>,
+			location: BoundedVec<u8, T::MaxSize>,
+			algorithm: KeyAlgorithm,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_guardian(&owner, &who)?;
+			ensure!(
+				!<ActiveRecoveries<T>>::contains_key(&owner),
+				Error::<T>::RecoveryAlreadyActive
+			);
+
+			let mut approvals = BoundedVec::new();
+			approvals.try_push(who.clone()).map_err(|_| Error::<T>::NotGuardian)?;
+			<ActiveRecoveries<T>>::insert(
+				&owner,
+				RecoveryRequest {
+					fingerprint,
+					location,
+					algorithm,
+					approvals,
+					initiated_at: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::RecoveryInitiated(owner, who));
+			Ok(())
+		}
+
+		/// Adds the caller's approval to the recovery in progress for `owner`.
+		#[pallet::weight(T::WeightInfo::approve_recovery())]
+		#[pallet::call_index(13)]
+		pub fn approve_recovery(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_guardian(&owner, &who)?;
+			<ActiveRecoveries<T>>::try_mutate(&owner, |recovery| -> DispatchResult {
+				let recovery = recovery.as_mut().ok_or(Error::<T>::NoActiveRecovery)?;
+				ensure!(!recovery.approvals.contains(&who), Error::<T>::AlreadyApproved);
+				recovery.approvals.try_push(who.clone()).map_err(|_| Error::<T>::NotGuardian)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RecoveryApproved(owner, who));
+			Ok(())
+		}
+
+		/// Lets the owner stop a recovery of its keys before it completes.
+		#[pallet::weight(T::WeightInfo::cancel_recovery())]
+		#[pallet::call_index(14)]
+		pub fn cancel_recovery(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(<ActiveRecoveries<T>>::contains_key(&who), Error::<T>::NoActiveRecovery);
+			<ActiveRecoveries<T>>::remove(&who);
+
+			Self::deposit_event(Event::RecoveryCancelled(who));
+			Ok(())
+		}
+
+		/// Completes a recovery once enough guardians have approved it and the recovery delay has
+		/// passed. Every key `owner` has announced is revoked and the proposed recovery key is
+		/// installed in their place. `key_count` must be at least the number of keys `owner`
+		/// currently holds.
+		#[pallet::weight(T::WeightInfo::complete_recovery(*key_count))]
+		#[pallet::call_index(15)]
+		pub fn complete_recovery(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			key_count: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let recovery =
+				<ActiveRecoveries<T>>::get(&owner).ok_or(Error::<T>::NoActiveRecovery)?;
+			let threshold = <Guardians<T>>::get(&owner).ok_or(Error::<T>::NoGuardians)?.threshold;
+			ensure!(
+				recovery.approvals.len() as u32 >= threshold,
+				Error::<T>::RecoveryThresholdNotMet
+			);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >=
+					recovery.initiated_at.saturating_add(T::RecoveryDelay::get()),
+				Error::<T>::RecoveryDelayNotElapsed
+			);
+
+			let fingerprints: Vec<_> = <IssuedKeys<T>>::iter_key_prefix(&owner).collect();
+			ensure!(fingerprints.len() as u32 <= key_count, Error::<T>::KeyCountWitnessTooLow);
+			for fingerprint in fingerprints {
+				Self::remove_key(&owner, &fingerprint);
+				Self::deposit_event(Event::KeyRevoked(fingerprint, owner.clone()));
+			}
+
+			Self::insert_key(
+				&owner,
+				&recovery.fingerprint,
+				&recovery.location,
+				KeyMetadata { purpose: KeyPurpose::Recovery, algorithm: recovery.algorithm },
+				None,
+			)?;
+			<ActiveRecoveries<T>>::remove(&owner);
+
+			Self::deposit_event(Event::KeyRecovered(recovery.fingerprint, owner));
 			Ok(())
 		}
 	}
@@ -0,0 +1,82 @@
+//! Storage migrations for the keystore pallet.
+
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+
+use crate::{Config, Pallet};
+
+pub mod v1 {
+	use super::*;
+	use crate::{
+		CurrentEncryptionKeyId, EncryptionKeyRecord, EncryptionKeyVersions, IssuedEncryptionKeys,
+		NextEncryptionKeyId,
+	};
+
+	/// Records the encryption key each account issued before keys were versioned as its version
+	/// 0, so it can still be looked up, revoked and used to match older ciphertexts. The block it
+	/// was issued in is not known, so the version is dated to the block of the upgrade.
+	pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut keys = 0u64;
+			let mut migrated = 0u64;
+			for (who, key) in IssuedEncryptionKeys::<T>::iter() {
+				keys += 1;
+				if NextEncryptionKeyId::<T>::contains_key(&who) {
+					continue;
+				}
+				EncryptionKeyVersions::<T>::insert(
+					&who,
+					0,
+					EncryptionKeyRecord { key, created_at: now, revoked: false },
+				);
+				NextEncryptionKeyId::<T>::insert(&who, 1);
+				CurrentEncryptionKeyId::<T>::insert(&who, 0);
+				migrated += 1;
+			}
+			T::DbWeight::get()
+				.reads_writes(keys.saturating_mul(2).saturating_add(1), migrated.saturating_mul(3))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((IssuedEncryptionKeys::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				IssuedEncryptionKeys::<T>::iter_keys().count() as u32 == count,
+				"encryption keys were lost"
+			);
+			for (who, key) in IssuedEncryptionKeys::<T>::iter() {
+				let key_id = CurrentEncryptionKeyId::<T>::get(&who)
+					.ok_or("encryption key has no current version")?;
+				let record = EncryptionKeyVersions::<T>::get(&who, key_id)
+					.ok_or("current version has no record")?;
+				ensure!(record.key == key, "current version does not match the issued key");
+				ensure!(
+					NextEncryptionKeyId::<T>::get(&who) > key_id,
+					"next key id would overwrite the current version"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrates the keystore pallet from storage version 0 to 1.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
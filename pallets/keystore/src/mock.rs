@@ -1,12 +1,15 @@
 use crate as pallet_keystore;
 use frame_support::{derive_impl, parameter_types};
 use frame_system as system;
-use sp_core::{ConstU32, H256};
+use sp_core::{ConstU32, ConstU64, H256};
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
 
+pub type Balance = u128;
+
 type Block = frame_system::mocking::MockBlock<Test>;
 
 // Configure a mock runtime to test the pallet.
@@ -15,6 +18,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		KeystoreModule: pallet_keystore::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
@@ -41,7 +45,7 @@ impl system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -50,16 +54,51 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 1;
+	pub const DepositPerByte: u128 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = [u8; 8];
+	type MaxFreezes = ();
+	type RuntimeFreezeReason = ();
+}
+
 impl pallet_keystore::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxSize = ConstU32<1024>;
+	type MaxKeyHistory = ConstU32<2>;
+	type MaxExpiringKeysPerBlock = ConstU32<2>;
+	type MaxEncryptionKeys = ConstU32<3>;
+	type MaxKeyDelegates = ConstU32<2>;
+	type Currency = Balances;
+	type DepositPerByte = DepositPerByte;
+	type MaxGuardians = ConstU32<3>;
+	type RecoveryDelay = ConstU64<10>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	RuntimeGenesisConfig { system: Default::default() }
-		.build_storage()
-		.unwrap()
-		.into()
+	let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: pallet_balances::GenesisConfig { balances: vec![(1, 10_000), (2, 10_000)] },
+	}
+	.build_storage()
+	.unwrap()
+	.into();
+	// Signing benchmarks generate their keys through the host keystore.
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+	ext
 }
@@ -1,7 +1,13 @@
-use crate::mock::*;
-use frame_support::assert_ok;
-use sp_core::ConstU32;
-use sp_runtime::BoundedVec;
+use crate::{
+	migrations, mock::*, DelegatePermissions, EncryptionKeyRecord, Error, IssuedEncryptionKeys,
+	KeyAlgorithm, KeyDetails, KeyMetadata, KeyPurpose,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_core::{ecdsa, ed25519, sr25519, ConstU32, Pair};
+use sp_runtime::{BoundedVec, MultiSignature, MultiSigner};
 
 #[test]
 fn test_issue_key() {
@@ -13,7 +19,10 @@ fn test_issue_key() {
 		assert_ok!(KeystoreModule::announce_key(
 			RuntimeOrigin::signed(1),
 			luke.clone(),
-			skywalker.clone()
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
 		));
 		System::assert_last_event(crate::Event::KeyAnnounced(luke, 1).into());
 	});
@@ -29,7 +38,10 @@ fn test_revoke_key() {
 		assert_ok!(KeystoreModule::announce_key(
 			RuntimeOrigin::signed(1),
 			BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap(),
-			skywalker.clone()
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
 		));
 		System::assert_last_event(
 			crate::Event::KeyAnnounced(
@@ -49,6 +61,969 @@ fn test_issue_encryption_key() {
 		System::set_block_number(1);
 		let luke = [0; 32];
 		assert_ok!(KeystoreModule::issue_encryption_key(RuntimeOrigin::signed(1), luke.clone()));
-		System::assert_last_event(crate::Event::EncryptionKeyIssued(1).into());
+		System::assert_last_event(crate::Event::EncryptionKeyIssued(1, 0).into());
+	});
+}
+
+#[test]
+fn test_issue_too_many_encryption_keys() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for key_id in 0..3 {
+			assert_ok!(KeystoreModule::issue_encryption_key(RuntimeOrigin::signed(1), [1; 32]));
+			assert_ok!(KeystoreModule::revoke_encryption_key(RuntimeOrigin::signed(1), key_id));
+		}
+		// Revoked keys are kept, so they still count towards the limit.
+		assert_noop!(
+			KeystoreModule::issue_encryption_key(RuntimeOrigin::signed(1), [2; 32]),
+			Error::<Test>::TooManyEncryptionKeys
+		);
+		assert_ok!(KeystoreModule::issue_encryption_key(RuntimeOrigin::signed(2), [2; 32]));
+	});
+}
+
+#[test]
+fn test_rotate_key() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap();
+		let leia = BoundedVec::<u8, ConstU32<1024>>::try_from("Leia".as_bytes().to_vec()).unwrap();
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
+		));
+		assert_ok!(KeystoreModule::rotate_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			leia.clone(),
+			skywalker.clone()
+		));
+		System::assert_last_event(crate::Event::KeyRotated(luke.clone(), leia.clone(), 1).into());
+		assert_eq!(KeystoreModule::key(1, luke.clone()), None);
+		assert_eq!(KeystoreModule::key(1, leia.clone()), Some(skywalker));
+		assert_eq!(KeystoreModule::key_predecessor(1, leia.clone()), Some(luke.clone()));
+		assert_eq!(
+			KeystoreModule::key_history(1).into_inner(),
+			vec![crate::KeyRotation {
+				old_fingerprint: luke,
+				new_fingerprint: leia,
+				rotated_at: 1
+			}]
+		);
+	});
+}
+
+#[test]
+fn test_revoking_rotated_key_clears_predecessor() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap();
+		let leia = BoundedVec::<u8, ConstU32<1024>>::try_from("Leia".as_bytes().to_vec()).unwrap();
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
+		));
+		assert_ok!(KeystoreModule::rotate_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			leia.clone(),
+			skywalker.clone()
+		));
+		assert_ok!(KeystoreModule::revoke_key(RuntimeOrigin::signed(1), leia.clone()));
+		assert_eq!(KeystoreModule::key_predecessor(1, leia.clone()), None);
+
+		// Announcing the same fingerprint again must not inherit the old rotation link.
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			leia.clone(),
+			skywalker,
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
+		));
+		assert_eq!(KeystoreModule::key_predecessor(1, leia), None);
+	});
+}
+
+#[test]
+fn test_rotate_key_errors() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap();
+		let leia = BoundedVec::<u8, ConstU32<1024>>::try_from("Leia".as_bytes().to_vec()).unwrap();
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		assert_noop!(
+			KeystoreModule::rotate_key(
+				RuntimeOrigin::signed(1),
+				luke.clone(),
+				leia.clone(),
+				skywalker.clone()
+			),
+			Error::<Test>::KeyDoesNotExist
+		);
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
+		));
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			leia.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
+		));
+		assert_noop!(
+			KeystoreModule::rotate_key(RuntimeOrigin::signed(1), luke, leia, skywalker),
+			Error::<Test>::KeyExists
+		);
+	});
+}
+
+#[test]
+fn test_key_history_drops_oldest_rotation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let keys: Vec<BoundedVec<u8, ConstU32<1024>>> = (0..4u8)
+			.map(|i| BoundedVec::<u8, ConstU32<1024>>::try_from(vec![i]).unwrap())
+			.collect();
+		let location =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			keys[0].clone(),
+			location.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
+		));
+		for i in 0..3 {
+			assert_ok!(KeystoreModule::rotate_key(
+				RuntimeOrigin::signed(1),
+				keys[i].clone(),
+				keys[i + 1].clone(),
+				location.clone()
+			));
+		}
+		let history = KeystoreModule::key_history(1);
+		assert_eq!(history.len(), 2);
+		assert_eq!(history[0].old_fingerprint, keys[1]);
+		assert_eq!(history[1].new_fingerprint, keys[3]);
+	});
+}
+
+#[test]
+fn test_announced_key_expires() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap();
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			Some(3)
+		));
+		assert_eq!(KeystoreModule::key_expiry(1, luke.clone()), Some(3));
+
+		System::set_block_number(2);
+		KeystoreModule::on_initialize(2);
+		assert_eq!(KeystoreModule::key(1, luke.clone()), Some(skywalker));
+
+		System::set_block_number(3);
+		KeystoreModule::on_initialize(3);
+		System::assert_last_event(crate::Event::KeyExpired(luke.clone(), 1).into());
+		assert_eq!(KeystoreModule::key(1, luke.clone()), None);
+		assert_eq!(KeystoreModule::key_expiry(1, luke), None);
+		assert!(KeystoreModule::expiring_keys(3).is_empty());
+	});
+}
+
+#[test]
+fn test_revoked_key_does_not_expire_later() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap();
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			Some(3)
+		));
+		assert_ok!(KeystoreModule::revoke_key(RuntimeOrigin::signed(1), luke.clone()));
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
+		));
+
+		System::set_block_number(3);
+		KeystoreModule::on_initialize(3);
+		assert_eq!(KeystoreModule::key(1, luke), Some(skywalker));
+	});
+}
+
+#[test]
+fn test_removed_keys_free_their_expiry_slot() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = |i: u8| BoundedVec::<u8, ConstU32<1024>>::try_from(vec![i]).unwrap();
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		for i in 0..2u8 {
+			assert_ok!(KeystoreModule::announce_key(
+				RuntimeOrigin::signed(1),
+				key(i),
+				skywalker.clone(),
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				Some(6)
+			));
+		}
+
+		// Revoking a key gives its slot in the expiry block back.
+		assert_ok!(KeystoreModule::revoke_key(RuntimeOrigin::signed(1), key(0)));
+		assert_eq!(KeystoreModule::expiring_keys(6).into_inner(), vec![(1, key(1))]);
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			key(2),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			Some(6)
+		));
+
+		// Rotating a key cancels its expiry.
+		assert_ok!(KeystoreModule::rotate_key(RuntimeOrigin::signed(1), key(1), key(3), skywalker));
+		assert_eq!(KeystoreModule::key_expiry(1, key(3)), None);
+		assert_eq!(KeystoreModule::expiring_keys(6).into_inner(), vec![(1, key(2))]);
+
+		assert_ok!(KeystoreModule::revoke_key(RuntimeOrigin::signed(1), key(2)));
+		assert!(!crate::ExpiringKeys::<Test>::contains_key(6));
+	});
+}
+
+#[test]
+fn test_announce_key_expiry_errors() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		assert_noop!(
+			KeystoreModule::announce_key(
+				RuntimeOrigin::signed(1),
+				BoundedVec::<u8, ConstU32<1024>>::try_from(vec![0]).unwrap(),
+				skywalker.clone(),
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				Some(5)
+			),
+			Error::<Test>::ExpiryInPast
+		);
+		for i in 0..2u8 {
+			assert_ok!(KeystoreModule::announce_key(
+				RuntimeOrigin::signed(1),
+				BoundedVec::<u8, ConstU32<1024>>::try_from(vec![i]).unwrap(),
+				skywalker.clone(),
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				Some(6)
+			));
+		}
+		assert_noop!(
+			KeystoreModule::announce_key(
+				RuntimeOrigin::signed(1),
+				BoundedVec::<u8, ConstU32<1024>>::try_from(vec![2]).unwrap(),
+				skywalker,
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				Some(6)
+			),
+			Error::<Test>::TooManyExpiringKeys
+		);
+	});
+}
+
+#[test]
+fn test_keys_by_purpose() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap();
+		let leia = BoundedVec::<u8, ConstU32<1024>>::try_from("Leia".as_bytes().to_vec()).unwrap();
+		let han = BoundedVec::<u8, ConstU32<1024>>::try_from("Han".as_bytes().to_vec()).unwrap();
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			skywalker.clone(),
+			KeyPurpose::Encryption,
+			KeyAlgorithm::X25519,
+			None
+		));
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			leia.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Ed25519,
+			None
+		));
+		assert_eq!(
+			KeystoreModule::key_metadata(1, luke.clone()),
+			Some(KeyMetadata { purpose: KeyPurpose::Encryption, algorithm: KeyAlgorithm::X25519 })
+		);
+		assert_eq!(KeystoreModule::keys_by_purpose(&1, KeyPurpose::Encryption), vec![luke.clone()]);
+		assert_eq!(KeystoreModule::keys_by_purpose(&1, KeyPurpose::Signing), vec![leia]);
+		assert!(KeystoreModule::keys_by_purpose(&1, KeyPurpose::Recovery).is_empty());
+
+		// A rotated key keeps the purpose of the key it replaces.
+		assert_ok!(KeystoreModule::rotate_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			han.clone(),
+			skywalker
+		));
+		assert_eq!(KeystoreModule::key_metadata(1, luke), None);
+		assert_eq!(KeystoreModule::keys_by_purpose(&1, KeyPurpose::Encryption), vec![han]);
+	});
+}
+
+#[test]
+fn test_encryption_key_versions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(KeystoreModule::issue_encryption_key(RuntimeOrigin::signed(1), [1; 32]));
+		System::set_block_number(2);
+		assert_ok!(KeystoreModule::issue_encryption_key(RuntimeOrigin::signed(1), [2; 32]));
+		System::assert_last_event(crate::Event::EncryptionKeyIssued(1, 1).into());
+
+		assert_eq!(KeystoreModule::encryption_key(1), Some([2; 32]));
+		assert_eq!(KeystoreModule::current_encryption_key_id(1), Some(1));
+		assert_eq!(
+			KeystoreModule::encryption_key_version(1, 0),
+			Some(EncryptionKeyRecord { key: [1; 32], created_at: 1, revoked: false })
+		);
+
+		// Revoking an older key leaves the current one in place.
+		assert_ok!(KeystoreModule::revoke_encryption_key(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(crate::Event::EncryptionKeyRevoked(1, 0).into());
+		assert!(KeystoreModule::encryption_key_version(1, 0).unwrap().revoked);
+		assert_eq!(KeystoreModule::encryption_key(1), Some([2; 32]));
+
+		assert_ok!(KeystoreModule::revoke_encryption_key(RuntimeOrigin::signed(1), 1));
+		assert_eq!(KeystoreModule::encryption_key(1), None);
+		assert_eq!(KeystoreModule::current_encryption_key_id(1), None);
+		assert_eq!(KeystoreModule::encryption_key_version(1, 1).unwrap().key, [2; 32]);
+	});
+}
+
+#[test]
+fn test_revoke_encryption_key_errors() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			KeystoreModule::revoke_encryption_key(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::EncryptionKeyDoesNotExist
+		);
+		assert_ok!(KeystoreModule::issue_encryption_key(RuntimeOrigin::signed(1), [1; 32]));
+		assert_noop!(
+			KeystoreModule::revoke_encryption_key(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::EncryptionKeyDoesNotExist
+		);
+		assert_ok!(KeystoreModule::revoke_encryption_key(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			KeystoreModule::revoke_encryption_key(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::EncryptionKeyAlreadyRevoked
+		);
+	});
+}
+
+#[test]
+fn test_announce_key_with_proof() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let location =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		let pair = sr25519::Pair::from_seed(&[1; 32]);
+		let signature = pair.sign(&KeystoreModule::key_ownership_payload(&1));
+		assert_ok!(KeystoreModule::announce_key_with_proof(
+			RuntimeOrigin::signed(1),
+			MultiSigner::Sr25519(pair.public()),
+			MultiSignature::Sr25519(signature),
+			location.clone(),
+			KeyPurpose::Authentication,
+			None
+		));
+
+		let fingerprint =
+			BoundedVec::<u8, ConstU32<1024>>::try_from(pair.public().0.to_vec()).unwrap();
+		System::assert_last_event(crate::Event::KeyAnnounced(fingerprint.clone(), 1).into());
+		assert_eq!(KeystoreModule::key(1, fingerprint.clone()), Some(location));
+		assert_eq!(KeystoreModule::proven_key(1, fingerprint.clone()), Some(()));
+		assert_eq!(
+			KeystoreModule::key_metadata(1, fingerprint.clone()),
+			Some(KeyMetadata {
+				purpose: KeyPurpose::Authentication,
+				algorithm: KeyAlgorithm::Sr25519
+			})
+		);
+		assert_eq!(KeystoreModule::key_proof_nonce(1), 1);
+
+		assert_ok!(KeystoreModule::revoke_key(RuntimeOrigin::signed(1), fingerprint.clone()));
+		assert_eq!(KeystoreModule::proven_key(1, fingerprint), None);
+	});
+}
+
+#[test]
+fn test_announce_key_with_proof_supports_ed25519_and_ecdsa() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let location =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+
+		let ed_pair = ed25519::Pair::from_seed(&[2; 32]);
+		let signature = ed_pair.sign(&KeystoreModule::key_ownership_payload(&1));
+		assert_ok!(KeystoreModule::announce_key_with_proof(
+			RuntimeOrigin::signed(1),
+			MultiSigner::Ed25519(ed_pair.public()),
+			MultiSignature::Ed25519(signature),
+			location.clone(),
+			KeyPurpose::Signing,
+			None
+		));
+
+		let ecdsa_pair = ecdsa::Pair::from_seed(&[3; 32]);
+		let signature = ecdsa_pair.sign(&KeystoreModule::key_ownership_payload(&1));
+		assert_ok!(KeystoreModule::announce_key_with_proof(
+			RuntimeOrigin::signed(1),
+			MultiSigner::Ecdsa(ecdsa_pair.public()),
+			MultiSignature::Ecdsa(signature),
+			location,
+			KeyPurpose::Signing,
+			None
+		));
+		assert_eq!(KeystoreModule::key_proof_nonce(1), 2);
+	});
+}
+
+#[test]
+fn test_announce_key_with_invalid_proof() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let location =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		let pair = sr25519::Pair::from_seed(&[1; 32]);
+
+		// A proof made for another account is rejected.
+		let signature = pair.sign(&KeystoreModule::key_ownership_payload(&2));
+		assert_noop!(
+			KeystoreModule::announce_key_with_proof(
+				RuntimeOrigin::signed(1),
+				MultiSigner::Sr25519(pair.public()),
+				MultiSignature::Sr25519(signature),
+				location.clone(),
+				KeyPurpose::Signing,
+				None
+			),
+			Error::<Test>::InvalidKeyProof
+		);
+
+		// A proof cannot be replayed once the nonce has moved on.
+		let signature = pair.sign(&KeystoreModule::key_ownership_payload(&1));
+		assert_ok!(KeystoreModule::announce_key_with_proof(
+			RuntimeOrigin::signed(1),
+			MultiSigner::Sr25519(pair.public()),
+			MultiSignature::Sr25519(signature.clone()),
+			location.clone(),
+			KeyPurpose::Signing,
+			None
+		));
+		let fingerprint =
+			BoundedVec::<u8, ConstU32<1024>>::try_from(pair.public().0.to_vec()).unwrap();
+		assert_ok!(KeystoreModule::revoke_key(RuntimeOrigin::signed(1), fingerprint));
+		assert_noop!(
+			KeystoreModule::announce_key_with_proof(
+				RuntimeOrigin::signed(1),
+				MultiSigner::Sr25519(pair.public()),
+				MultiSignature::Sr25519(signature),
+				location,
+				KeyPurpose::Signing,
+				None
+			),
+			Error::<Test>::InvalidKeyProof
+		);
+	});
+}
+
+#[test]
+fn test_query_helpers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap();
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			Some(3)
+		));
+		assert_eq!(
+			KeystoreModule::keys_of(&1),
+			vec![KeyDetails {
+				fingerprint: luke.to_vec(),
+				location: skywalker.to_vec(),
+				metadata: Some(KeyMetadata {
+					purpose: KeyPurpose::Signing,
+					algorithm: KeyAlgorithm::Rsa
+				}),
+				expires_at: Some(3),
+				proven: false,
+			}]
+		);
+		assert!(KeystoreModule::is_key_valid(&1, &luke));
+		assert!(!KeystoreModule::is_key_valid(&2, &luke));
+		assert!(!KeystoreModule::is_key_valid(&1, "Leia".as_bytes()));
+
+		// A key is no longer valid once its expiry block is reached.
+		System::set_block_number(3);
+		assert!(!KeystoreModule::is_key_valid(&1, &luke));
+
+		assert_eq!(KeystoreModule::current_encryption_key(&1), None);
+		assert_ok!(KeystoreModule::issue_encryption_key(RuntimeOrigin::signed(1), [1; 32]));
+		assert_eq!(
+			KeystoreModule::current_encryption_key(&1),
+			Some((0, EncryptionKeyRecord { key: [1; 32], created_at: 3, revoked: false }))
+		);
+	});
+}
+
+#[test]
+fn test_key_deposits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap();
+		let leia = BoundedVec::<u8, ConstU32<1024>>::try_from("Leia".as_bytes().to_vec()).unwrap();
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		let organa =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Organa".as_bytes().to_vec()).unwrap();
+
+		// The deposit covers every byte of the fingerprint and location.
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), 13);
+		assert_eq!(KeystoreModule::key_deposit(1, luke.clone()), Some(13));
+
+		// Rotating returns the old deposit and reserves one for the new key.
+		assert_ok!(KeystoreModule::rotate_key(
+			RuntimeOrigin::signed(1),
+			luke.clone(),
+			leia.clone(),
+			organa.clone()
+		));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(KeystoreModule::key_deposit(1, luke), None);
+		assert_eq!(KeystoreModule::key_deposit(1, leia.clone()), Some(10));
+
+		assert_ok!(KeystoreModule::revoke_key(RuntimeOrigin::signed(1), leia.clone()));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10_000);
+
+		// Expired keys are refunded too.
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			leia.clone(),
+			organa,
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			Some(2)
+		));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		System::set_block_number(2);
+		KeystoreModule::on_initialize(2);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// Accounts that cannot cover the deposit cannot announce keys.
+		assert_noop!(
+			KeystoreModule::announce_key(
+				RuntimeOrigin::signed(3),
+				leia,
+				skywalker,
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				None
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn test_rotate_to_larger_key_reserves_more() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let small = BoundedVec::<u8, ConstU32<1024>>::try_from(vec![1]).unwrap();
+		let large_fingerprint = BoundedVec::<u8, ConstU32<1024>>::try_from(vec![2; 1024]).unwrap();
+		let large_location = BoundedVec::<u8, ConstU32<1024>>::try_from(vec![3; 1024]).unwrap();
+
+		assert_ok!(KeystoreModule::announce_key(
+			RuntimeOrigin::signed(1),
+			small.clone(),
+			small.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		assert_ok!(KeystoreModule::rotate_key(
+			RuntimeOrigin::signed(1),
+			small.clone(),
+			large_fingerprint.clone(),
+			large_location
+		));
+		assert_eq!(Balances::reserved_balance(1), 2048);
+		assert_eq!(KeystoreModule::key_deposit(1, small), None);
+		assert_eq!(KeystoreModule::key_deposit(1, large_fingerprint.clone()), Some(2048));
+
+		assert_ok!(KeystoreModule::revoke_key(RuntimeOrigin::signed(1), large_fingerprint));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10_000);
+	});
+}
+
+#[test]
+fn test_key_delegates() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap();
+		let skywalker =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Skywalker".as_bytes().to_vec()).unwrap();
+		let announce_only = DelegatePermissions { announce: true, revoke: false };
+
+		// Accounts that were never appointed cannot act for the owner.
+		assert_noop!(
+			KeystoreModule::announce_key_for(
+				RuntimeOrigin::signed(2),
+				1,
+				luke.clone(),
+				skywalker.clone(),
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				None
+			),
+			Error::<Test>::NotKeyDelegate
+		);
+
+		assert_ok!(KeystoreModule::add_key_delegate(RuntimeOrigin::signed(1), 2, announce_only));
+		System::assert_last_event(crate::Event::KeyDelegateAdded(1, 2, announce_only).into());
+
+		// The key belongs to the owner, who also pays its deposit.
+		assert_ok!(KeystoreModule::announce_key_for(
+			RuntimeOrigin::signed(2),
+			1,
+			luke.clone(),
+			skywalker.clone(),
+			KeyPurpose::Signing,
+			KeyAlgorithm::Rsa,
+			None
+		));
+		System::assert_last_event(crate::Event::KeyAnnouncedByDelegate(luke.clone(), 1, 2).into());
+		assert_eq!(KeystoreModule::key(1, luke.clone()), Some(skywalker));
+		assert_eq!(KeystoreModule::key(2, luke.clone()), None);
+		assert_eq!(Balances::reserved_balance(1), 13);
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		assert_noop!(
+			KeystoreModule::revoke_key_for(RuntimeOrigin::signed(2), 1, luke.clone()),
+			Error::<Test>::DelegatePermissionDenied
+		);
+
+		assert_ok!(KeystoreModule::add_key_delegate(
+			RuntimeOrigin::signed(1),
+			2,
+			DelegatePermissions { announce: false, revoke: true }
+		));
+		assert_ok!(KeystoreModule::revoke_key_for(RuntimeOrigin::signed(2), 1, luke.clone()));
+		System::assert_last_event(crate::Event::KeyRevokedByDelegate(luke.clone(), 1, 2).into());
+		assert_eq!(KeystoreModule::key(1, luke.clone()), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		assert_ok!(KeystoreModule::remove_key_delegate(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(crate::Event::KeyDelegateRemoved(1, 2).into());
+		assert_noop!(
+			KeystoreModule::revoke_key_for(RuntimeOrigin::signed(2), 1, luke),
+			Error::<Test>::NotKeyDelegate
+		);
+		assert_noop!(
+			KeystoreModule::remove_key_delegate(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::NotKeyDelegate
+		);
+	});
+}
+
+#[test]
+fn test_too_many_key_delegates() {
+	new_test_ext().execute_with(|| {
+		let permissions = DelegatePermissions { announce: true, revoke: true };
+		assert_ok!(KeystoreModule::add_key_delegate(RuntimeOrigin::signed(1), 2, permissions));
+		assert_ok!(KeystoreModule::add_key_delegate(RuntimeOrigin::signed(1), 3, permissions));
+		assert_eq!(KeystoreModule::key_delegate_count(1), 2);
+		assert_noop!(
+			KeystoreModule::add_key_delegate(RuntimeOrigin::signed(1), 4, permissions),
+			Error::<Test>::TooManyKeyDelegates
+		);
+
+		// Changing the permissions of an existing delegate does not take another slot.
+		assert_ok!(KeystoreModule::add_key_delegate(
+			RuntimeOrigin::signed(1),
+			3,
+			DelegatePermissions { announce: true, revoke: false }
+		));
+		assert_eq!(KeystoreModule::key_delegate_count(1), 2);
+
+		assert_ok!(KeystoreModule::remove_key_delegate(RuntimeOrigin::signed(1), 2));
+		assert_eq!(KeystoreModule::key_delegate_count(1), 1);
+		assert_ok!(KeystoreModule::add_key_delegate(RuntimeOrigin::signed(1), 4, permissions));
+	});
+}
+
+#[test]
+fn test_set_guardians_errors() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let guardians =
+			|accounts: Vec<u64>| BoundedVec::<u64, ConstU32<3>>::try_from(accounts).unwrap();
+
+		assert_noop!(
+			KeystoreModule::set_guardians(RuntimeOrigin::signed(1), guardians(vec![2, 3]), 0),
+			Error::<Test>::InvalidGuardianThreshold
+		);
+		assert_noop!(
+			KeystoreModule::set_guardians(RuntimeOrigin::signed(1), guardians(vec![2, 3]), 3),
+			Error::<Test>::InvalidGuardianThreshold
+		);
+		assert_noop!(
+			KeystoreModule::set_guardians(RuntimeOrigin::signed(1), guardians(vec![2, 2]), 1),
+			Error::<Test>::InvalidGuardian
+		);
+		assert_noop!(
+			KeystoreModule::set_guardians(RuntimeOrigin::signed(1), guardians(vec![1, 2]), 1),
+			Error::<Test>::InvalidGuardian
+		);
+		assert_noop!(
+			KeystoreModule::clear_guardians(RuntimeOrigin::signed(1)),
+			Error::<Test>::NoGuardians
+		);
+
+		assert_ok!(KeystoreModule::set_guardians(
+			RuntimeOrigin::signed(1),
+			guardians(vec![2, 3]),
+			2
+		));
+		System::assert_last_event(crate::Event::GuardiansSet(1, 2).into());
+		assert_ok!(KeystoreModule::clear_guardians(RuntimeOrigin::signed(1)));
+		System::assert_last_event(crate::Event::GuardiansCleared(1).into());
+		assert_eq!(KeystoreModule::guardians(1), None);
+	});
+}
+
+#[test]
+fn test_recover_keys_with_guardians() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let luke = BoundedVec::<u8, ConstU32<1024>>::try_from("Luke".as_bytes().to_vec()).unwrap();
+		let leia = BoundedVec::<u8, ConstU32<1024>>::try_from("Leia".as_bytes().to_vec()).unwrap();
+		let ben = BoundedVec::<u8, ConstU32<1024>>::try_from("Ben".as_bytes().to_vec()).unwrap();
+		let location =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Tatooine".as_bytes().to_vec()).unwrap();
+		for fingerprint in [&luke, &leia] {
+			assert_ok!(KeystoreModule::announce_key(
+				RuntimeOrigin::signed(1),
+				fingerprint.clone(),
+				location.clone(),
+				KeyPurpose::Signing,
+				KeyAlgorithm::Rsa,
+				None
+			));
+		}
+		assert_ok!(KeystoreModule::set_guardians(
+			RuntimeOrigin::signed(1),
+			BoundedVec::try_from(vec![2, 3, 4]).unwrap(),
+			2
+		));
+
+		assert_noop!(
+			KeystoreModule::initiate_recovery(
+				RuntimeOrigin::signed(5),
+				1,
+				ben.clone(),
+				location.clone(),
+				KeyAlgorithm::Ed25519
+			),
+			Error::<Test>::NotGuardian
+		);
+		assert_ok!(KeystoreModule::initiate_recovery(
+			RuntimeOrigin::signed(2),
+			1,
+			ben.clone(),
+			location.clone(),
+			KeyAlgorithm::Ed25519
+		));
+		System::assert_last_event(crate::Event::RecoveryInitiated(1, 2).into());
+		assert_noop!(
+			KeystoreModule::initiate_recovery(
+				RuntimeOrigin::signed(3),
+				1,
+				ben.clone(),
+				location.clone(),
+				KeyAlgorithm::Ed25519
+			),
+			Error::<Test>::RecoveryAlreadyActive
+		);
+
+		assert_noop!(
+			KeystoreModule::complete_recovery(RuntimeOrigin::signed(2), 1, 2),
+			Error::<Test>::RecoveryThresholdNotMet
+		);
+		assert_noop!(
+			KeystoreModule::approve_recovery(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_ok!(KeystoreModule::approve_recovery(RuntimeOrigin::signed(3), 1));
+		System::assert_last_event(crate::Event::RecoveryApproved(1, 3).into());
+
+		// The owner gets the recovery delay to object before anything changes.
+		assert_noop!(
+			KeystoreModule::complete_recovery(RuntimeOrigin::signed(2), 1, 2),
+			Error::<Test>::RecoveryDelayNotElapsed
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			KeystoreModule::complete_recovery(RuntimeOrigin::signed(2), 1, 1),
+			Error::<Test>::KeyCountWitnessTooLow
+		);
+		assert_ok!(KeystoreModule::complete_recovery(RuntimeOrigin::signed(2), 1, 2));
+		System::assert_last_event(crate::Event::KeyRecovered(ben.clone(), 1).into());
+
+		assert_eq!(KeystoreModule::key(1, luke), None);
+		assert_eq!(KeystoreModule::key(1, leia), None);
+		assert_eq!(KeystoreModule::key(1, ben.clone()), Some(location));
+		assert_eq!(
+			KeystoreModule::key_metadata(1, ben),
+			Some(KeyMetadata { purpose: KeyPurpose::Recovery, algorithm: KeyAlgorithm::Ed25519 })
+		);
+		assert_eq!(Balances::reserved_balance(1), 11);
+		assert_eq!(KeystoreModule::active_recovery(1), None);
+	});
+}
+
+#[test]
+fn test_owner_cancels_recovery() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let ben = BoundedVec::<u8, ConstU32<1024>>::try_from("Ben".as_bytes().to_vec()).unwrap();
+		let location =
+			BoundedVec::<u8, ConstU32<1024>>::try_from("Tatooine".as_bytes().to_vec()).unwrap();
+		assert_ok!(KeystoreModule::set_guardians(
+			RuntimeOrigin::signed(1),
+			BoundedVec::try_from(vec![2]).unwrap(),
+			1
+		));
+		assert_ok!(KeystoreModule::initiate_recovery(
+			RuntimeOrigin::signed(2),
+			1,
+			ben,
+			location,
+			KeyAlgorithm::Ed25519
+		));
+
+		// Guardians cannot be changed while a recovery is pending.
+		assert_noop!(
+			KeystoreModule::clear_guardians(RuntimeOrigin::signed(1)),
+			Error::<Test>::RecoveryAlreadyActive
+		);
+
+		assert_ok!(KeystoreModule::cancel_recovery(RuntimeOrigin::signed(1)));
+		System::assert_last_event(crate::Event::RecoveryCancelled(1).into());
+		System::set_block_number(11);
+		assert_noop!(
+			KeystoreModule::complete_recovery(RuntimeOrigin::signed(2), 1, 0),
+			Error::<Test>::NoActiveRecovery
+		);
+		assert_noop!(
+			KeystoreModule::cancel_recovery(RuntimeOrigin::signed(1)),
+			Error::<Test>::NoActiveRecovery
+		);
+	});
+}
+
+#[test]
+fn migrate_encryption_key_versions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		StorageVersion::new(0).put::<KeystoreModule>();
+		// Keys issued before versioning existed are only in `IssuedEncryptionKeys`.
+		IssuedEncryptionKeys::<Test>::insert(1, [1; 32]);
+
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(KeystoreModule::on_chain_storage_version(), 1);
+		assert_eq!(
+			KeystoreModule::encryption_key_version(1, 0),
+			Some(EncryptionKeyRecord { key: [1; 32], created_at: 5, revoked: false })
+		);
+		assert_eq!(KeystoreModule::current_encryption_key_id(1), Some(0));
+		assert_eq!(KeystoreModule::next_encryption_key_id(1), 1);
+
+		// Issuing a new key keeps the migrated one, which can still be revoked.
+		assert_ok!(KeystoreModule::issue_encryption_key(RuntimeOrigin::signed(1), [2; 32]));
+		assert_eq!(
+			KeystoreModule::encryption_key_version(1, 0).map(|record| record.key),
+			Some([1; 32])
+		);
+		assert_ok!(KeystoreModule::revoke_encryption_key(RuntimeOrigin::signed(1), 0));
+
+		// The migration only runs from version 0.
+		IssuedEncryptionKeys::<Test>::insert(2, [3; 32]);
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(KeystoreModule::current_encryption_key_id(2), None);
 	});
 }
@@ -36,6 +36,20 @@ pub trait WeightInfo {
 	fn revoke_one_of_many_keys() -> Weight;
 	fn issue_encryption_key() -> Weight;
 	fn issue_a_ton_of_encryption_keys() -> Weight;
+	fn rotate_key() -> Weight;
+	fn expire_keys(n: u32) -> Weight;
+	fn revoke_encryption_key() -> Weight;
+	fn announce_key_with_proof() -> Weight;
+	fn add_key_delegate() -> Weight;
+	fn remove_key_delegate() -> Weight;
+	fn announce_key_for() -> Weight;
+	fn revoke_key_for() -> Weight;
+	fn set_guardians() -> Weight;
+	fn clear_guardians() -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn approve_recovery() -> Weight;
+	fn cancel_recovery() -> Weight;
+	fn complete_recovery(n: u32) -> Weight;
 }
 
 /// Weights for pallet_keystore using the Substrate node and recommended hardware.
@@ -44,10 +58,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
 	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added:
+	/// 70069, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:0 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:0 w:1)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
 	fn announce_key() -> Weight {
-		Weight::from_parts(11_000_000, 5581)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(11_000_000, 71059)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
 	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
@@ -76,10 +105,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
 	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added: 70069,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:1 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:1 w:1)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyPredecessor` (r:0 w:1)
+	/// Proof: `Keystore::KeyPredecessor` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
 	fn revoke_key() -> Weight {
-		Weight::from_parts(13_000_000, 5581)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(13_000_000, 71059)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
 	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
@@ -89,17 +136,317 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Keystore::NextEncryptionKeyId` (r:1 w:1)
+	/// Proof: `Keystore::NextEncryptionKeyId` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::EncryptionKeyVersions` (r:0 w:1)
+	/// Proof: `Keystore::EncryptionKeyVersions` (`max_values`: None, `max_size`: Some(101), added:
+	/// 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::CurrentEncryptionKeyId` (r:0 w:1)
+	/// Proof: `Keystore::CurrentEncryptionKeyId` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Keystore::IssuedEncryptionKeys` (r:0 w:1)
 	/// Proof: `Keystore::IssuedEncryptionKeys` (`max_values`: None, `max_size`: Some(80), added:
 	/// 2555, mode: `MaxEncodedLen`)
 	fn issue_encryption_key() -> Weight {
-		Weight::from_parts(8_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(8_000_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Keystore::NextEncryptionKeyId` (r:1 w:1)
+	/// Proof: `Keystore::NextEncryptionKeyId` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::EncryptionKeyVersions` (r:0 w:1)
+	/// Proof: `Keystore::EncryptionKeyVersions` (`max_values`: None, `max_size`: Some(101), added:
+	/// 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::CurrentEncryptionKeyId` (r:0 w:1)
+	/// Proof: `Keystore::CurrentEncryptionKeyId` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Keystore::IssuedEncryptionKeys` (r:0 w:1)
 	/// Proof: `Keystore::IssuedEncryptionKeys` (`max_values`: None, `max_size`: Some(80), added:
 	/// 2555, mode: `MaxEncodedLen`)
 	fn issue_a_ton_of_encryption_keys() -> Weight {
-		Weight::from_parts(18_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(18_000_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Keystore::IssuedKeys` (r:2 w:2)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyHistory` (r:1 w:1)
+	/// Proof: `Keystore::KeyHistory` (`max_values`: None, `max_size`: Some(20538), added: 23013,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added: 70069,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:1 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyPredecessor` (r:0 w:2)
+	/// Proof: `Keystore::KeyPredecessor` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:1 w:2)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:1 w:2)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	fn rotate_key() -> Weight {
+		Weight::from_parts(21_000_000, 71059)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `Keystore::ExpiringKeys` (r:65 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added:
+	/// 70069, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:64 w:64)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeys` (r:0 w:64)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:64)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:64 w:64)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:64 w:64)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyPredecessor` (r:0 w:64)
+	/// Proof: `Keystore::KeyPredecessor` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn expire_keys(n: u32) -> Weight {
+		Weight::from_parts(4_000_000, 71059)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 70069).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Keystore::EncryptionKeyVersions` (r:1 w:1)
+	/// Proof: `Keystore::EncryptionKeyVersions` (`max_values`: None, `max_size`: Some(101), added:
+	/// 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::CurrentEncryptionKeyId` (r:1 w:1)
+	/// Proof: `Keystore::CurrentEncryptionKeyId` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedEncryptionKeys` (r:0 w:1)
+	/// Proof: `Keystore::IssuedEncryptionKeys` (`max_values`: None, `max_size`: Some(80), added:
+	/// 2555, mode: `MaxEncodedLen`)
+	fn revoke_encryption_key() -> Weight {
+		Weight::from_parts(12_000_000, 3566)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyProofNonce` (r:1 w:1)
+	/// Proof: `Keystore::KeyProofNonce` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added:
+	/// 70069, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:0 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ProvenKeys` (r:0 w:1)
+	/// Proof: `Keystore::ProvenKeys` (`max_values`: None, `max_size`: Some(1082), added: 3557,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:0 w:1)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	fn announce_key_with_proof() -> Weight {
+		Weight::from_parts(68_000_000, 71059)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Keystore::KeyDelegates` (r:1 w:1)
+	/// Proof: `Keystore::KeyDelegates` (`max_values`: None, `max_size`: Some(97), added: 2572,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDelegateCount` (r:1 w:1)
+	/// Proof: `Keystore::KeyDelegateCount` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	fn add_key_delegate() -> Weight {
+		Weight::from_parts(12_000_000, 3562)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Keystore::KeyDelegates` (r:1 w:1)
+	/// Proof: `Keystore::KeyDelegates` (`max_values`: None, `max_size`: Some(97), added: 2572,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDelegateCount` (r:1 w:1)
+	/// Proof: `Keystore::KeyDelegateCount` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	fn remove_key_delegate() -> Weight {
+		Weight::from_parts(12_000_000, 3562)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Keystore::KeyDelegates` (r:1 w:0)
+	/// Proof: `Keystore::KeyDelegates` (`max_values`: None, `max_size`: Some(97), added: 2572,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added: 70069,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:0 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:0 w:1)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	fn announce_key_for() -> Weight {
+		Weight::from_parts(19_000_000, 71059)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Keystore::KeyDelegates` (r:1 w:0)
+	/// Proof: `Keystore::KeyDelegates` (`max_values`: None, `max_size`: Some(97), added: 2572,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:1 w:1)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added: 70069,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:1 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ProvenKeys` (r:0 w:1)
+	/// Proof: `Keystore::ProvenKeys` (`max_values`: None, `max_size`: Some(1082), added: 3557,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyPredecessor` (r:0 w:1)
+	/// Proof: `Keystore::KeyPredecessor` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	fn revoke_key_for() -> Weight {
+		Weight::from_parts(17_000_000, 71059)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::Guardians` (r:0 w:1)
+	/// Proof: `Keystore::Guardians` (`max_values`: None, `max_size`: Some(313), added: 2788, mode:
+	/// `MaxEncodedLen`)
+	fn set_guardians() -> Weight {
+		Weight::from_parts(15_000_000, 5861)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Keystore::Guardians` (r:1 w:1)
+	/// Proof: `Keystore::Guardians` (`max_values`: None, `max_size`: Some(313), added: 2788, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	fn clear_guardians() -> Weight {
+		Weight::from_parts(13_000_000, 5861)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Keystore::Guardians` (r:1 w:0)
+	/// Proof: `Keystore::Guardians` (`max_values`: None, `max_size`: Some(313), added: 2788, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(21_000_000, 5861)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Keystore::Guardians` (r:1 w:0)
+	/// Proof: `Keystore::Guardians` (`max_values`: None, `max_size`: Some(313), added: 2788, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	fn approve_recovery() -> Weight {
+		Weight::from_parts(22_000_000, 5861)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		Weight::from_parts(14_000_000, 5861)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::Guardians` (r:1 w:0)
+	/// Proof: `Keystore::Guardians` (`max_values`: None, `max_size`: Some(313), added: 2788, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeys` (r:1001 w:1001)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:1001 w:1001)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1001 w:1001)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added: 70069,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:1001 w:1001)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1001)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ProvenKeys` (r:0 w:1000)
+	/// Proof: `Keystore::ProvenKeys` (`max_values`: None, `max_size`: Some(1082), added: 3557,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyPredecessor` (r:0 w:1001)
+	/// Proof: `Keystore::KeyPredecessor` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn complete_recovery(n: u32) -> Weight {
+		Weight::from_parts(38_000_000, 71059)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 70069).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
 	}
 }
 
@@ -108,14 +455,29 @@ impl WeightInfo for () {
 	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
 	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added:
+	/// 70069, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:0 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:0 w:1)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
 	fn announce_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
-		//  Estimated: `5581`
+		//  Estimated: `71059`
 		// Minimum execution time: 11_000_000 picoseconds.
-		Weight::from_parts(11_000_000, 5581)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(11_000_000, 71059)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
 	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
@@ -156,14 +518,32 @@ impl WeightInfo for () {
 	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
 	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added: 70069,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:1 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:1 w:1)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyPredecessor` (r:0 w:1)
+	/// Proof: `Keystore::KeyPredecessor` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
 	fn revoke_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `134`
-		//  Estimated: `5581`
+		//  Estimated: `71059`
 		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(13_000_000, 5581)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(13_000_000, 71059)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
 	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
@@ -177,24 +557,380 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Keystore::NextEncryptionKeyId` (r:1 w:1)
+	/// Proof: `Keystore::NextEncryptionKeyId` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::EncryptionKeyVersions` (r:0 w:1)
+	/// Proof: `Keystore::EncryptionKeyVersions` (`max_values`: None, `max_size`: Some(101), added:
+	/// 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::CurrentEncryptionKeyId` (r:0 w:1)
+	/// Proof: `Keystore::CurrentEncryptionKeyId` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Keystore::IssuedEncryptionKeys` (r:0 w:1)
 	/// Proof: `Keystore::IssuedEncryptionKeys` (`max_values`: None, `max_size`: Some(80), added:
 	/// 2555, mode: `MaxEncodedLen`)
 	fn issue_encryption_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Measured:  `6`
+		//  Estimated: `3517`
 		// Minimum execution time: 7_000_000 picoseconds.
-		Weight::from_parts(8_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(8_000_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Keystore::NextEncryptionKeyId` (r:1 w:1)
+	/// Proof: `Keystore::NextEncryptionKeyId` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::EncryptionKeyVersions` (r:0 w:1)
+	/// Proof: `Keystore::EncryptionKeyVersions` (`max_values`: None, `max_size`: Some(101), added:
+	/// 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::CurrentEncryptionKeyId` (r:0 w:1)
+	/// Proof: `Keystore::CurrentEncryptionKeyId` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Keystore::IssuedEncryptionKeys` (r:0 w:1)
 	/// Proof: `Keystore::IssuedEncryptionKeys` (`max_values`: None, `max_size`: Some(80), added:
 	/// 2555, mode: `MaxEncodedLen`)
 	fn issue_a_ton_of_encryption_keys() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Measured:  `6`
+		//  Estimated: `3517`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Keystore::IssuedKeys` (r:2 w:2)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyHistory` (r:1 w:1)
+	/// Proof: `Keystore::KeyHistory` (`max_values`: None, `max_size`: Some(20538), added: 23013,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added: 70069,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:1 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyPredecessor` (r:0 w:2)
+	/// Proof: `Keystore::KeyPredecessor` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:1 w:2)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:1 w:2)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	fn rotate_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `134`
+		//  Estimated: `71059`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 71059)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `Keystore::ExpiringKeys` (r:65 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added:
+	/// 70069, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:64 w:64)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeys` (r:0 w:64)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:64)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:64 w:64)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:64 w:64)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyPredecessor` (r:0 w:64)
+	/// Proof: `Keystore::KeyPredecessor` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn expire_keys(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6 + n * (128 ±0)`
+		//  Estimated: `71059 + n * (70069 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(4_000_000, 71059)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 70069).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Keystore::EncryptionKeyVersions` (r:1 w:1)
+	/// Proof: `Keystore::EncryptionKeyVersions` (`max_values`: None, `max_size`: Some(101), added:
+	/// 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::CurrentEncryptionKeyId` (r:1 w:1)
+	/// Proof: `Keystore::CurrentEncryptionKeyId` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedEncryptionKeys` (r:0 w:1)
+	/// Proof: `Keystore::IssuedEncryptionKeys` (`max_values`: None, `max_size`: Some(80), added:
+	/// 2555, mode: `MaxEncodedLen`)
+	fn revoke_encryption_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `171`
+		//  Estimated: `3566`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3566)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyProofNonce` (r:1 w:1)
+	/// Proof: `Keystore::KeyProofNonce` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added:
+	/// 70069, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:0 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ProvenKeys` (r:0 w:1)
+	/// Proof: `Keystore::ProvenKeys` (`max_values`: None, `max_size`: Some(1082), added: 3557,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:0 w:1)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	fn announce_key_with_proof() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `103`
+		//  Estimated: `71059`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(68_000_000, 71059)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Keystore::KeyDelegates` (r:1 w:1)
+	/// Proof: `Keystore::KeyDelegates` (`max_values`: None, `max_size`: Some(97), added: 2572,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDelegateCount` (r:1 w:1)
+	/// Proof: `Keystore::KeyDelegateCount` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	fn add_key_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `3562`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3562)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Keystore::KeyDelegates` (r:1 w:1)
+	/// Proof: `Keystore::KeyDelegates` (`max_values`: None, `max_size`: Some(97), added: 2572,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDelegateCount` (r:1 w:1)
+	/// Proof: `Keystore::KeyDelegateCount` (`max_values`: None, `max_size`: Some(52), added: 2527,
+	/// mode: `MaxEncodedLen`)
+	fn remove_key_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `3562`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3562)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Keystore::KeyDelegates` (r:1 w:0)
+	/// Proof: `Keystore::KeyDelegates` (`max_values`: None, `max_size`: Some(97), added: 2572,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added: 70069,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:0 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:0 w:1)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	fn announce_key_for() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `71059`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 71059)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Keystore::KeyDelegates` (r:1 w:0)
+	/// Proof: `Keystore::KeyDelegates` (`max_values`: None, `max_size`: Some(97), added: 2572,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeys` (r:1 w:1)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:1 w:1)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1 w:1)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added: 70069,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:1 w:1)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ProvenKeys` (r:0 w:1)
+	/// Proof: `Keystore::ProvenKeys` (`max_values`: None, `max_size`: Some(1082), added: 3557,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyPredecessor` (r:0 w:1)
+	/// Proof: `Keystore::KeyPredecessor` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	fn revoke_key_for() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		//  Estimated: `71059`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 71059)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::Guardians` (r:0 w:1)
+	/// Proof: `Keystore::Guardians` (`max_values`: None, `max_size`: Some(313), added: 2788, mode:
+	/// `MaxEncodedLen`)
+	fn set_guardians() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `5861`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 5861)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Keystore::Guardians` (r:1 w:1)
+	/// Proof: `Keystore::Guardians` (`max_values`: None, `max_size`: Some(313), added: 2788, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	fn clear_guardians() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		//  Estimated: `5861`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 5861)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Keystore::Guardians` (r:1 w:0)
+	/// Proof: `Keystore::Guardians` (`max_values`: None, `max_size`: Some(313), added: 2788, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	fn initiate_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		//  Estimated: `5861`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 5861)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Keystore::Guardians` (r:1 w:0)
+	/// Proof: `Keystore::Guardians` (`max_values`: None, `max_size`: Some(313), added: 2788, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	fn approve_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2312`
+		//  Estimated: `5861`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 5861)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2101`
+		//  Estimated: `5861`
 		// Minimum execution time: 13_000_000 picoseconds.
-		Weight::from_parts(18_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(14_000_000, 5861)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Keystore::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Keystore::ActiveRecoveries` (`max_values`: None, `max_size`: Some(2396), added:
+	/// 4871, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::Guardians` (r:1 w:0)
+	/// Proof: `Keystore::Guardians` (`max_values`: None, `max_size`: Some(313), added: 2788, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeys` (r:1001 w:1001)
+	/// Proof: `Keystore::IssuedKeys` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyDeposits` (r:1001 w:1001)
+	/// Proof: `Keystore::KeyDeposits` (`max_values`: None, `max_size`: Some(1096), added: 3571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Keystore::ExpiringKeys` (r:1001 w:1001)
+	/// Proof: `Keystore::ExpiringKeys` (`max_values`: None, `max_size`: Some(67594), added: 70069,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyExpiry` (r:1001 w:1001)
+	/// Proof: `Keystore::KeyExpiry` (`max_values`: None, `max_size`: Some(1088), added: 3563,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::IssuedKeyMetadata` (r:0 w:1001)
+	/// Proof: `Keystore::IssuedKeyMetadata` (`max_values`: None, `max_size`: Some(1082), added:
+	/// 3557, mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::ProvenKeys` (r:0 w:1000)
+	/// Proof: `Keystore::ProvenKeys` (`max_values`: None, `max_size`: Some(1082), added: 3557,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Keystore::KeyPredecessor` (r:0 w:1001)
+	/// Proof: `Keystore::KeyPredecessor` (`max_values`: None, `max_size`: Some(2116), added: 4591,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn complete_recovery(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2340`
+		//  Estimated: `71059`
+		// Minimum execution time: 37_000_000 picoseconds.
+		Weight::from_parts(38_000_000, 71059)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 70069).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
 	}
 }
@@ -0,0 +1,114 @@
+//! RPC interface for the trust pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_trust_runtime_api::TrustApi as TrustRuntimeApi;
+
+/// Trust RPC methods.
+#[rpc(client, server)]
+pub trait TrustApi<BlockHash, AccountId> {
+	/// Lists the addresses `account` has issued trust for, with the level of that trust.
+	#[method(name = "trust_trusts")]
+	fn trusts(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<(AccountId, u8)>>;
+
+	/// Lists the addresses that have issued trust for `account`, with the level of that trust.
+	#[method(name = "trust_trustedBy")]
+	fn trusted_by(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, u8)>>;
+
+	/// Finds a shortest chain of unrevoked trust from `from` to `to`, following at most
+	/// `max_depth` links. The runtime caps the depth at its own limit.
+	#[method(name = "trust_path")]
+	fn trust_path(
+		&self,
+		from: AccountId,
+		to: AccountId,
+		max_depth: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<AccountId>>>;
+}
+
+/// Provides RPC methods to query the web of trust.
+pub struct Trust<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Trust<C, Block> {
+	/// Creates a new instance of the trust RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(e: ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), "Unable to query trust.", Some(e.to_string()))
+}
+
+impl<C, Block, AccountId> TrustApiServer<<Block as BlockT>::Hash, AccountId> for Trust<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TrustRuntimeApi<Block, AccountId>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn trusts(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, u8)>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().trusts(at_hash, account).map_err(runtime_error)
+	}
+
+	fn trusted_by(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, u8)>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().trusted_by(at_hash, account).map_err(runtime_error)
+	}
+
+	fn trust_path(
+		&self,
+		from: AccountId,
+		to: AccountId,
+		max_depth: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Vec<AccountId>>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.trust_path(at_hash, from, to, max_depth)
+			.map_err(runtime_error)
+	}
+}
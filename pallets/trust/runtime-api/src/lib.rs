@@ -0,0 +1,24 @@
+//! Runtime API definition for the trust pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// Lookups into the web of trust kept by the trust pallet.
+	pub trait TrustApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// The addresses `account` has issued trust for, with the level of that trust.
+		fn trusts(account: AccountId) -> Vec<(AccountId, u8)>;
+		/// The addresses that have issued trust for `account`, with the level of that trust.
+		fn trusted_by(account: AccountId) -> Vec<(AccountId, u8)>;
+		/// A shortest chain of unrevoked trust from `from` to `to`, including both ends, following
+		/// at most `max_depth` links.
+		fn trust_path(from: AccountId, to: AccountId, max_depth: u32) -> Option<Vec<AccountId>>;
+	}
+}
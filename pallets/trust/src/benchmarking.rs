@@ -5,13 +5,24 @@ use super::*;
 use crate::Pallet as Trust;
 
 use frame_benchmarking::{account as benchmark_account, v2::*};
-use frame_support::BoundedVec;
-use frame_system::RawOrigin;
+use frame_support::{
+	traits::{Get, Hooks},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use scale_info::prelude::vec;
+use sp_runtime::traits::Saturating;
 
 pub fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// A context tag of the largest allowed size.
+pub fn get_context<T: Config>() -> TrustContextOf<T> {
+	let size = T::MaxTrustContextSize::get() as usize;
+	TrustContextOf::<T>::try_from(vec![0; size]).unwrap()
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -39,7 +50,12 @@ mod benchmarks {
 		let caller: T::AccountId = whitelisted_caller();
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller.clone()), target.clone());
+		_(
+			RawOrigin::Signed(caller.clone()),
+			target.clone(),
+			T::MaxTrustLevel::get(),
+			Some(get_context::<T>()),
+		);
 
 		assert_eq!(CurrentIssued::<T>::get(), 1);
 		assert!(TrustIssuance::<T>::contains_key(caller.clone(), target.clone()));
@@ -52,14 +68,24 @@ mod benchmarks {
 		for i in 0..1000 {
 			let target: T::AccountId = benchmark_account("target", i, 0);
 			let caller: T::AccountId = benchmark_account("caller", i, 0);
-			Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
+			Trust::<T>::issue_trust(
+				RawOrigin::Signed(caller.clone()).into(),
+				target.clone(),
+				T::MaxTrustLevel::get(),
+				None,
+			)?;
 		}
 
 		let target: T::AccountId = whitelisted_caller();
 		let caller: T::AccountId = whitelisted_caller();
 
 		#[extrinsic_call]
-		issue_trust(RawOrigin::Signed(caller.clone()), target.clone());
+		issue_trust(
+			RawOrigin::Signed(caller.clone()),
+			target.clone(),
+			T::MaxTrustLevel::get(),
+			Some(get_context::<T>()),
+		);
 
 		assert_eq!(CurrentIssued::<T>::get(), 1001);
 		assert!(TrustIssuance::<T>::contains_key(caller.clone(), target.clone()));
@@ -86,7 +112,12 @@ mod benchmarks {
 		for i in 0..100_000 {
 			let target: T::AccountId = benchmark_account("target", i, 0);
 			let caller: T::AccountId = benchmark_account("caller", i, 0);
-			Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
+			Trust::<T>::issue_trust(
+				RawOrigin::Signed(caller.clone()).into(),
+				target.clone(),
+				T::MaxTrustLevel::get(),
+				None,
+			)?;
 		}
 
 		for i in 0..100_000 {
@@ -111,7 +142,12 @@ mod benchmarks {
 	fn remove_trust() -> Result<(), BenchmarkError> {
 		let target: T::AccountId = whitelisted_caller();
 		let caller: T::AccountId = whitelisted_caller();
-		Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
+		Trust::<T>::issue_trust(
+			RawOrigin::Signed(caller.clone()).into(),
+			target.clone(),
+			T::MaxTrustLevel::get(),
+			None,
+		)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), target.clone());
@@ -127,12 +163,22 @@ mod benchmarks {
 		for i in 0..1000 {
 			let target: T::AccountId = benchmark_account("target", i, 0);
 			let caller: T::AccountId = benchmark_account("caller", i, 0);
-			Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
+			Trust::<T>::issue_trust(
+				RawOrigin::Signed(caller.clone()).into(),
+				target.clone(),
+				T::MaxTrustLevel::get(),
+				None,
+			)?;
 		}
 
 		let target: T::AccountId = whitelisted_caller();
 		let caller: T::AccountId = whitelisted_caller();
-		Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
+		Trust::<T>::issue_trust(
+			RawOrigin::Signed(caller.clone()).into(),
+			target.clone(),
+			T::MaxTrustLevel::get(),
+			None,
+		)?;
 
 		#[extrinsic_call]
 		remove_trust(RawOrigin::Signed(caller.clone()), target.clone());
@@ -162,6 +208,8 @@ mod benchmarks {
 		for i in 0..1000 {
 			let target: T::AccountId = benchmark_account("target", i, 0);
 			let caller: T::AccountId = benchmark_account("caller", i, 0);
+			// Spread the requests out so their expiries do not fill a single block.
+			frame_system::Pallet::<T>::set_block_number(i.into());
 			Trust::<T>::request_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
 		}
 
@@ -234,6 +282,8 @@ mod benchmarks {
 		for i in 0..1000 {
 			let target: T::AccountId = benchmark_account("target", i, 0);
 			let caller: T::AccountId = benchmark_account("caller", i, 0);
+			// Spread the requests out so their expiries do not fill a single block.
+			frame_system::Pallet::<T>::set_block_number(i.into());
 			Trust::<T>::request_trust(RawOrigin::Signed(caller.clone()).into(), target.clone())?;
 		}
 
@@ -249,5 +299,84 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn update_trust_level() -> Result<(), BenchmarkError> {
+		let target: T::AccountId = whitelisted_caller();
+		let caller: T::AccountId = whitelisted_caller();
+		Trust::<T>::issue_trust(RawOrigin::Signed(caller.clone()).into(), target.clone(), 0, None)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			target.clone(),
+			T::MaxTrustLevel::get(),
+			Some(get_context::<T>()),
+		);
+
+		assert_eq!(Trust::<T>::trust_level(&caller, &target), Some(T::MaxTrustLevel::get()));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_trust_request() -> Result<(), BenchmarkError> {
+		let requester: T::AccountId = benchmark_account("requester", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		Trust::<T>::request_trust(RawOrigin::Signed(requester.clone()).into(), caller.clone())?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			requester.clone(),
+			T::MaxTrustLevel::get(),
+			Some(get_context::<T>()),
+		);
+
+		assert!(!TrustRequestList::<T>::contains_key(&requester, &caller));
+		assert_eq!(Trust::<T>::trust_level(&caller, &requester), Some(T::MaxTrustLevel::get()));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn decline_trust_request() -> Result<(), BenchmarkError> {
+		let requester: T::AccountId = benchmark_account("requester", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		Trust::<T>::request_trust(RawOrigin::Signed(requester.clone()).into(), caller.clone())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), requester.clone());
+
+		assert!(!TrustRequestList::<T>::contains_key(&requester, &caller));
+		assert_last_event::<T>(Event::TrustRequestDeclined(requester, caller).into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_trust_requests(
+		n: Linear<0, { T::MaxExpiringTrustRequestsPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target: T::AccountId = whitelisted_caller();
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let expires_at: BlockNumberFor<T> =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::TrustRequestExpiry::get());
+
+		for i in 0..n {
+			let requester: T::AccountId = benchmark_account("requester", i, 0);
+			Trust::<T>::request_trust(RawOrigin::Signed(requester).into(), target.clone())?;
+		}
+
+		#[block]
+		{
+			Trust::<T>::on_initialize(expires_at);
+		}
+
+		assert_eq!(CurrentRequests::<T>::get(), 0);
+		assert_eq!(TrustRequestList::<T>::iter().count(), 0);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Trust, crate::mock::new_test_ext(), crate::mock::Test);
 }
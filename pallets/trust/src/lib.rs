@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -11,14 +13,20 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
 #[frame_support::pallet]
 pub mod pallet {
+	use alloc::{
+		collections::{BTreeMap, VecDeque},
+		vec,
+		vec::Vec,
+	};
 	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::One;
+	use sp_runtime::traits::{One, Saturating};
 
 	use crate::weights::WeightInfo;
 
@@ -30,9 +38,49 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		/// The maximum size of a trust parameter string
 		type MaxTrustParameterSize: Get<u32>;
+		/// The highest trust level an account can issue
+		type MaxTrustLevel: Get<u8>;
+		/// The maximum size of the context tag attached to issued trust
+		type MaxTrustContextSize: Get<u32>;
+		/// The maximum number of trust links a path search follows
+		type MaxTrustPathDepth: Get<u32>;
+		/// How many blocks a trust request stays open before it expires
+		type TrustRequestExpiry: Get<BlockNumberFor<Self>>;
+		/// The maximum number of trust requests that may expire in a single block
+		type MaxExpiringTrustRequestsPerBlock: Get<u32>;
+	}
+
+	/// Trust issued by one account to another.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct TrustRecord<Context> {
+		/// How strongly the issuer trusts the subject, from 0 up to `MaxTrustLevel`.
+		pub level: u8,
+		/// An optional tag saying what the trust applies to, such as a skill or community.
+		pub context: Option<Context>,
+	}
+
+	pub type TrustContextOf<T> = BoundedVec<u8, <T as Config>::MaxTrustContextSize>;
+
+	pub type TrustRecordOf<T> = TrustRecord<TrustContextOf<T>>;
+
+	/// How much trust one account currently takes part in.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct TrustCount {
+		/// The number of addresses the account has issued trust for.
+		pub issued: u32,
+		/// The number of addresses that have issued trust for the account.
+		pub received: u32,
+		/// The number of addresses the account has revoked trust for.
+		pub revoked: u32,
 	}
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::type_value]
@@ -41,19 +89,39 @@ pub mod pallet {
 	}
 	#[pallet::storage]
 	#[pallet::getter(fn get_current_trust_count)]
-	/// The total number of trust actions currently active
+	/// The total number of trust issuances currently active
 	pub type CurrentIssued<T: Config> =
 		StorageValue<Value = u32, QueryKind = ValueQuery, OnEmpty = DefaultCurrent<T>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_trust_issuance)]
-	/// A Map of lists of all addresses that each address has issued trust for
-	pub type TrustIssuance<T: Config> =
-		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, u32>;
+	/// A Map of lists of all addresses that each address has issued trust for, with the level
+	/// and context of that trust
+	pub type TrustIssuance<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		TrustRecordOf<T>,
+	>;
+
+	#[pallet::storage]
+	/// Reverse index of `TrustIssuance`, mapping each address to the addresses that have issued
+	/// trust for it
+	pub type TrustedBy<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_current_non_trust_count)]
-	/// The current number of _non_trust actions currently active
+	/// The total number of trust revocations currently active
 	pub type CurrentRevoked<T: Config> =
 		StorageValue<Value = u32, QueryKind = ValueQuery, OnEmpty = DefaultCurrent<T>>;
 
@@ -61,11 +129,18 @@ pub mod pallet {
 	#[pallet::getter(fn get_non_trust_issuance)]
 	/// A Map of lists of all addresses that each address has revoked trust for
 	pub type TrustRevocation<T: Config> =
-		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, u32>;
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn trust_counts)]
+	/// The number of trust issuances and revocations each account takes part in. Accounts with
+	/// no trust activity have no entry.
+	pub type TrustCounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, TrustCount, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_current_trust_requests)]
-	/// The current number of trust requests currently active
+	/// The total number of trust requests currently open
 	pub type CurrentRequests<T: Config> =
 		StorageValue<Value = u32, QueryKind = ValueQuery, OnEmpty = DefaultCurrent<T>>;
 
@@ -73,7 +148,31 @@ pub mod pallet {
 	#[pallet::getter(fn get_trust_request)]
 	/// A map listing all requests for trust from one account to another.
 	pub type TrustRequestList<T: Config> =
-		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, u32>;
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn trust_request_expiry)]
+	/// The block at which each open trust request expires.
+	pub type TrustRequestExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_trust_requests)]
+	/// Trust requests scheduled to expire at the start of a given block, as (requester, target)
+	/// pairs.
+	pub type ExpiringTrustRequests<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::AccountId, T::AccountId), T::MaxExpiringTrustRequestsPerBlock>,
+		ValueQuery,
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn trust_paramter_list)]
@@ -93,8 +192,10 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Announce that a trust parameter has been set
 		TrustParameterSet(T::AccountId),
-		/// Announce that an account has issued trust to another account
-		TrustIssued(T::AccountId, T::AccountId),
+		/// Announce that an account has issued trust to another account, with the trust level
+		TrustIssued(T::AccountId, T::AccountId, u8),
+		/// Announce that an account has changed the level of trust it issued to another account
+		TrustLevelUpdated(T::AccountId, T::AccountId, u8),
 		/// Announce that an account has revoked trust from another account
 		TrustRevoked(T::AccountId, T::AccountId),
 		/// Announce that an account has requested trust from another account
@@ -105,6 +206,15 @@ pub mod pallet {
 		TrustIssuanceRemoved(T::AccountId, T::AccountId),
 		/// Announce that an account has removed a trust revocation from another account
 		TrustRevocationRemoved(T::AccountId, T::AccountId),
+		/// Announce that an account accepted a trust request and issued trust. Contains the
+		/// requester, the account that accepted and the trust level.
+		TrustRequestAccepted(T::AccountId, T::AccountId, u8),
+		/// Announce that an account declined a trust request. Contains the requester and the
+		/// account that declined.
+		TrustRequestDeclined(T::AccountId, T::AccountId),
+		/// Announce that a trust request expired without an answer. Contains the requester and
+		/// the account it was made to.
+		TrustRequestExpired(T::AccountId, T::AccountId),
 	}
 
 	#[pallet::error]
@@ -123,24 +233,167 @@ pub mod pallet {
 		TrustRevocationExists,
 		/// The requested trust revocation does not exist
 		TrustRevocationNotFound,
+		/// The trust level is higher than `MaxTrustLevel`
+		InvalidTrustLevel,
+		/// Too many trust requests are already scheduled to expire in the same block
+		TooManyExpiringTrustRequests,
 	}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Fully give `origin`'s trust to account `address`
-		#[pallet::weight(T::WeightInfo::issue_trust())]
-		#[pallet::call_index(0)]
-		pub fn issue_trust(origin: OriginFor<T>, address: T::AccountId) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Closes every trust request whose expiry falls on this block.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expiring = <ExpiringTrustRequests<T>>::take(now);
+			let count = expiring.len() as u32;
+
+			for (requester, target) in expiring {
+				// Requests that were answered, cancelled or placed again since being scheduled no
+				// longer carry this expiry and are left alone.
+				if <TrustRequestExpiries<T>>::get(&requester, &target) == Some(now) &&
+					Self::remove_trust_request(&requester, &target).is_ok()
+				{
+					Self::deposit_event(Event::TrustRequestExpired(requester, target));
+				}
+			}
+
+			T::WeightInfo::expire_trust_requests(count)
+		}
+	}
 
-			ensure!(!<TrustIssuance<T>>::contains_key(&who, &address), Error::<T>::TrustExists);
+	impl<T: Config> Pallet<T> {
+		/// Records trust from `who` to `address` at `level`, failing if it already exists.
+		fn insert_trust(
+			who: &T::AccountId,
+			address: &T::AccountId,
+			level: u8,
+			context: Option<TrustContextOf<T>>,
+		) -> DispatchResult {
+			ensure!(!<TrustIssuance<T>>::contains_key(who, address), Error::<T>::TrustExists);
+			ensure!(level <= T::MaxTrustLevel::get(), Error::<T>::InvalidTrustLevel);
 
 			let total: u32 = <CurrentIssued<T>>::get();
 			let new_total: u32 =
 				total.checked_add(One::one()).ok_or(Error::<T>::StorageOverflow)?;
-			<TrustIssuance<T>>::insert(&who, &address, total);
+			<TrustIssuance<T>>::insert(who, address, TrustRecord { level, context });
+			<TrustedBy<T>>::insert(address, who, ());
 			<CurrentIssued<T>>::put(new_total);
-			Self::deposit_event(Event::TrustIssued(who, address));
+			Self::update_counts(who, |counts| counts.issued.saturating_inc());
+			Self::update_counts(address, |counts| counts.received.saturating_inc());
+
+			Ok(())
+		}
+
+		/// Applies `f` to the trust counts of `who`, dropping the entry once every count is zero.
+		fn update_counts(who: &T::AccountId, f: impl FnOnce(&mut TrustCount)) {
+			<TrustCounts<T>>::mutate_exists(who, |maybe_counts| {
+				let mut counts = maybe_counts.take().unwrap_or_default();
+				f(&mut counts);
+				*maybe_counts = (counts != TrustCount::default()).then_some(counts);
+			});
+		}
+
+		/// Closes the trust request `requester` placed to `target`, along with its expiry.
+		fn remove_trust_request(requester: &T::AccountId, target: &T::AccountId) -> DispatchResult {
+			ensure!(
+				<TrustRequestList<T>>::contains_key(requester, target),
+				Error::<T>::TrustRequestNotFound
+			);
+
+			let total: u32 = <CurrentRequests<T>>::get();
+			let new_total: u32 =
+				total.checked_sub(One::one()).ok_or(Error::<T>::StorageOverflow)?;
+			<TrustRequestList<T>>::remove(requester, target);
+			<TrustRequestExpiries<T>>::remove(requester, target);
+			<CurrentRequests<T>>::put(new_total);
+
+			Ok(())
+		}
+
+		/// The level of trust `issuer` has issued to `subject`, if any.
+		pub fn trust_level(issuer: &T::AccountId, subject: &T::AccountId) -> Option<u8> {
+			<TrustIssuance<T>>::get(issuer, subject).map(|record| record.level)
+		}
+
+		/// Lists the addresses `account` has issued trust for, with the level of that trust.
+		pub fn trusts(account: &T::AccountId) -> Vec<(T::AccountId, u8)> {
+			<TrustIssuance<T>>::iter_prefix(account)
+				.map(|(subject, record)| (subject, record.level))
+				.collect()
+		}
+
+		/// Lists the addresses that have issued trust for `account`, with the level of that
+		/// trust.
+		pub fn trusted_by(account: &T::AccountId) -> Vec<(T::AccountId, u8)> {
+			<TrustedBy<T>>::iter_key_prefix(account)
+				.filter_map(|issuer| Some((issuer.clone(), Self::trust_level(&issuer, account)?)))
+				.collect()
+		}
+
+		/// Finds a shortest chain of trust from `from` to `to`, following at most `max_depth`
+		/// links and never more than `MaxTrustPathDepth`. A link counts only if its issuer has
+		/// not also revoked trust for its subject. Returns every address along the path,
+		/// including both ends, or `None` if there is no such path.
+		///
+		/// The search reads storage for every address it reaches, so it is meant for runtime API
+		/// queries rather than extrinsics.
+		pub fn trust_path(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			max_depth: u32,
+		) -> Option<Vec<T::AccountId>> {
+			if from == to {
+				return Some(vec![from.clone()]);
+			}
+			let max_depth = max_depth.min(T::MaxTrustPathDepth::get());
+
+			// Maps each address reached to the address it was first reached from.
+			let mut parents: BTreeMap<T::AccountId, T::AccountId> = BTreeMap::new();
+			let mut queue: VecDeque<(T::AccountId, u32)> = VecDeque::new();
+			queue.push_back((from.clone(), 0));
+
+			while let Some((current, depth)) = queue.pop_front() {
+				if depth >= max_depth {
+					continue;
+				}
+				for subject in <TrustIssuance<T>>::iter_key_prefix(&current) {
+					if subject == *from ||
+						parents.contains_key(&subject) ||
+						<TrustRevocation<T>>::contains_key(&current, &subject)
+					{
+						continue;
+					}
+					parents.insert(subject.clone(), current.clone());
+					if subject == *to {
+						let mut path = vec![subject];
+						while let Some(parent) = parents.get(path.last()?) {
+							path.push(parent.clone());
+						}
+						path.reverse();
+						return Some(path);
+					}
+					queue.push_back((subject, depth + 1));
+				}
+			}
+			None
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Give `origin`'s trust to account `address` at `level`, up to `MaxTrustLevel`, with an
+		/// optional `context` tag
+		#[pallet::weight(T::WeightInfo::issue_trust())]
+		#[pallet::call_index(0)]
+		pub fn issue_trust(
+			origin: OriginFor<T>,
+			address: T::AccountId,
+			level: u8,
+			context: Option<TrustContextOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::insert_trust(&who, &address, level, context)?;
+			Self::deposit_event(Event::TrustIssued(who, address, level));
 
 			Ok(())
 		}
@@ -153,16 +406,21 @@ pub mod pallet {
 
 			ensure!(<TrustIssuance<T>>::contains_key(&who, &address), Error::<T>::TrustNotFound);
 
-			let key = <CurrentIssued<T>>::get();
-			let new_key: u32 = key.checked_sub(One::one()).ok_or(Error::<T>::StorageOverflow)?;
+			let total: u32 = <CurrentIssued<T>>::get();
+			let new_total: u32 =
+				total.checked_sub(One::one()).ok_or(Error::<T>::StorageOverflow)?;
 			<TrustIssuance<T>>::remove(&who, &address);
-			<CurrentIssued<T>>::put(new_key);
+			<TrustedBy<T>>::remove(&address, &who);
+			<CurrentIssued<T>>::put(new_total);
+			Self::update_counts(&who, |counts| counts.issued.saturating_dec());
+			Self::update_counts(&address, |counts| counts.received.saturating_dec());
 			Self::deposit_event(Event::TrustIssuanceRemoved(address, who));
 
 			Ok(())
 		}
 
-		/// Place a request for `address` to issue explicit trust to the sender.
+		/// Place a request for `address` to issue explicit trust to the sender. The request
+		/// expires after `TrustRequestExpiry` blocks if `address` does not answer it.
 		#[pallet::weight(T::WeightInfo::request_trust())]
 		#[pallet::call_index(2)]
 		pub fn request_trust(origin: OriginFor<T>, address: T::AccountId) -> DispatchResult {
@@ -176,8 +434,16 @@ pub mod pallet {
 			let total: u32 = <CurrentRequests<T>>::get();
 			let new_total: u32 =
 				total.checked_add(One::one()).ok_or(Error::<T>::StorageOverflow)?;
+			let expires_at = <frame_system::Pallet<T>>::block_number()
+				.saturating_add(T::TrustRequestExpiry::get());
+			<ExpiringTrustRequests<T>>::try_mutate(expires_at, |expiring| {
+				expiring
+					.try_push((who.clone(), address.clone()))
+					.map_err(|_| Error::<T>::TooManyExpiringTrustRequests)
+			})?;
 			<CurrentRequests<T>>::put(new_total);
-			<TrustRequestList<T>>::insert(&who, &address, total);
+			<TrustRequestList<T>>::insert(&who, &address, ());
+			<TrustRequestExpiries<T>>::insert(&who, &address, expires_at);
 			Self::deposit_event(Event::TrustRequest(who, address));
 
 			Ok(())
@@ -189,15 +455,7 @@ pub mod pallet {
 		pub fn cancel_trust_request(origin: OriginFor<T>, address: T::AccountId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				<TrustRequestList<T>>::contains_key(&who, &address),
-				Error::<T>::TrustRequestNotFound
-			);
-
-			let key = <CurrentRequests<T>>::get();
-			let new_key: u32 = key.checked_sub(One::one()).ok_or(Error::<T>::StorageOverflow)?;
-			<TrustRequestList<T>>::remove(&who, &address);
-			<CurrentRequests<T>>::put(new_key);
+			Self::remove_trust_request(&who, &address)?;
 			Self::deposit_event(Event::TrustRequestRemoved(address, who));
 
 			Ok(())
@@ -217,10 +475,12 @@ pub mod pallet {
 				Error::<T>::TrustRevocationExists
 			);
 
-			let key: u32 = <CurrentRevoked<T>>::get();
-			let new_key: u32 = key.checked_add(One::one()).ok_or(Error::<T>::StorageOverflow)?;
-			<TrustRevocation<T>>::insert(&who, &address, key);
-			<CurrentRevoked<T>>::put(new_key);
+			let total: u32 = <CurrentRevoked<T>>::get();
+			let new_total: u32 =
+				total.checked_add(One::one()).ok_or(Error::<T>::StorageOverflow)?;
+			<TrustRevocation<T>>::insert(&who, &address, ());
+			<CurrentRevoked<T>>::put(new_total);
+			Self::update_counts(&who, |counts| counts.revoked.saturating_inc());
 			Self::deposit_event(Event::TrustRevoked(address, who));
 
 			Ok(())
@@ -237,10 +497,12 @@ pub mod pallet {
 				Error::<T>::TrustRevocationNotFound
 			);
 
-			let key: u32 = <CurrentRevoked<T>>::get();
-			let new_key: u32 = key.checked_sub(One::one()).ok_or(Error::<T>::StorageOverflow)?;
+			let total: u32 = <CurrentRevoked<T>>::get();
+			let new_total: u32 =
+				total.checked_sub(One::one()).ok_or(Error::<T>::StorageOverflow)?;
 			<TrustRevocation<T>>::remove(&who, &address);
-			<CurrentRevoked<T>>::put(new_key);
+			<CurrentRevoked<T>>::put(new_total);
+			Self::update_counts(&who, |counts| counts.revoked.saturating_dec());
 			Self::deposit_event(Event::TrustRevocationRemoved(address, who));
 
 			Ok(())
@@ -261,5 +523,68 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Change the level and context of the trust `origin` has already issued to `address`
+		#[pallet::weight(T::WeightInfo::update_trust_level())]
+		#[pallet::call_index(7)]
+		pub fn update_trust_level(
+			origin: OriginFor<T>,
+			address: T::AccountId,
+			level: u8,
+			context: Option<TrustContextOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(level <= T::MaxTrustLevel::get(), Error::<T>::InvalidTrustLevel);
+			<TrustIssuance<T>>::try_mutate(&who, &address, |record| -> DispatchResult {
+				let record = record.as_mut().ok_or(Error::<T>::TrustNotFound)?;
+				*record = TrustRecord { level, context };
+				Ok(())
+			})?;
+			Self::deposit_event(Event::TrustLevelUpdated(who, address, level));
+
+			Ok(())
+		}
+
+		/// Accept the trust request `requester` placed to `origin`, issuing trust to `requester`
+		/// at `level` with an optional `context` tag and closing the request
+		#[pallet::weight(T::WeightInfo::accept_trust_request())]
+		#[pallet::call_index(8)]
+		pub fn accept_trust_request(
+			origin: OriginFor<T>,
+			requester: T::AccountId,
+			level: u8,
+			context: Option<TrustContextOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				<TrustRequestList<T>>::contains_key(&requester, &who),
+				Error::<T>::TrustRequestNotFound
+			);
+
+			Self::insert_trust(&who, &requester, level, context)?;
+			Self::remove_trust_request(&requester, &who)?;
+			Self::deposit_event(Event::TrustIssued(who.clone(), requester.clone(), level));
+			Self::deposit_event(Event::TrustRequestAccepted(requester, who, level));
+
+			Ok(())
+		}
+
+		/// Decline the trust request `requester` placed to `origin`, closing it without issuing
+		/// trust
+		#[pallet::weight(T::WeightInfo::decline_trust_request())]
+		#[pallet::call_index(9)]
+		pub fn decline_trust_request(
+			origin: OriginFor<T>,
+			requester: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::remove_trust_request(&requester, &who)?;
+			Self::deposit_event(Event::TrustRequestDeclined(requester, who));
+
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,232 @@
+//! Storage migrations for the trust pallet.
+
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+use alloc::collections::BTreeMap;
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+
+use crate::{Config, Pallet};
+
+pub mod v1 {
+	use super::*;
+	use crate::{TrustIssuance, TrustRecord, TrustedBy};
+
+	/// Replaces the counter snapshots stored in `TrustIssuance` with trust records and builds
+	/// the `TrustedBy` index from them. Trust issued before levels existed was full trust, so it
+	/// is given `MaxTrustLevel`.
+	pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			TrustIssuance::<T>::translate::<u32, _>(|issuer, subject, _| {
+				translated += 1;
+				TrustedBy::<T>::insert(&subject, &issuer, ());
+				Some(TrustRecord { level: T::MaxTrustLevel::get(), context: None })
+			});
+			T::DbWeight::get().reads_writes(translated, translated.saturating_mul(2))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((TrustIssuance::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				TrustIssuance::<T>::iter().count() as u32 == count,
+				"trust issuances were lost"
+			);
+			ensure!(
+				TrustedBy::<T>::iter_keys().count() as u32 == count,
+				"trust index is incomplete"
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrates the trust pallet from storage version 0 to 1.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v2 {
+	use super::*;
+	use crate::{
+		CurrentIssued, CurrentRequests, CurrentRevoked, TrustCount, TrustCounts, TrustIssuance,
+		TrustRequestList, TrustRevocation,
+	};
+	use sp_runtime::traits::Saturating;
+
+	/// Drops the counter snapshots stored in `TrustRevocation` and `TrustRequestList`, then
+	/// recomputes `CurrentIssued`, `CurrentRevoked` and `CurrentRequests` and fills
+	/// `TrustCounts` from the entries that actually exist, since the old counters could drift.
+	pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut counts: BTreeMap<T::AccountId, TrustCount> = BTreeMap::new();
+
+			let mut issued = 0u32;
+			for (issuer, subject) in TrustIssuance::<T>::iter_keys() {
+				issued.saturating_inc();
+				counts.entry(issuer).or_default().issued.saturating_inc();
+				counts.entry(subject).or_default().received.saturating_inc();
+			}
+
+			let mut revoked = 0u32;
+			TrustRevocation::<T>::translate::<u32, _>(|issuer, _, _| {
+				revoked.saturating_inc();
+				counts.entry(issuer).or_default().revoked.saturating_inc();
+				Some(())
+			});
+
+			let mut requests = 0u32;
+			TrustRequestList::<T>::translate::<u32, _>(|_, _, _| {
+				requests.saturating_inc();
+				Some(())
+			});
+
+			let accounts = counts.len() as u64;
+			for (who, count) in counts {
+				TrustCounts::<T>::insert(who, count);
+			}
+			CurrentIssued::<T>::put(issued);
+			CurrentRevoked::<T>::put(revoked);
+			CurrentRequests::<T>::put(requests);
+
+			let entries = issued as u64 + revoked as u64 + requests as u64;
+			T::DbWeight::get()
+				.reads_writes(entries, (revoked as u64 + requests as u64).saturating_add(accounts))
+				.saturating_add(T::DbWeight::get().writes(3))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let revoked = TrustRevocation::<T>::iter_keys().count() as u32;
+			let requests = TrustRequestList::<T>::iter_keys().count() as u32;
+			Ok((revoked, requests).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (revoked, requests) =
+				<(u32, u32)>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				TrustRevocation::<T>::iter().count() as u32 == revoked,
+				"trust revocations were lost"
+			);
+			ensure!(
+				TrustRequestList::<T>::iter().count() as u32 == requests,
+				"trust requests were lost"
+			);
+			ensure!(CurrentRevoked::<T>::get() == revoked, "revocation total is wrong");
+			ensure!(CurrentRequests::<T>::get() == requests, "request total is wrong");
+			ensure!(
+				CurrentIssued::<T>::get() as usize == TrustIssuance::<T>::iter_keys().count(),
+				"issuance total is wrong"
+			);
+			let issued: u32 = TrustCounts::<T>::iter_values().map(|count| count.issued).sum();
+			ensure!(issued == CurrentIssued::<T>::get(), "per-account issuance counts are wrong");
+			Ok(())
+		}
+	}
+
+	/// Migrates the trust pallet from storage version 1 to 2.
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		InnerMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v3 {
+	use super::*;
+	use crate::{ExpiringTrustRequests, TrustRequestExpiries, TrustRequestList};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_runtime::traits::Saturating;
+
+	/// Schedules an expiry for every trust request placed before requests could expire. Each
+	/// request expires `TrustRequestExpiry` blocks after the upgrade, or in the first later
+	/// block with room if that block already holds `MaxExpiringTrustRequestsPerBlock`. If that
+	/// bound is zero no block can hold an expiry, and the requests are left without one.
+	pub struct InnerMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if T::MaxExpiringTrustRequestsPerBlock::get() == 0 {
+				return Weight::zero();
+			}
+
+			let mut expires_at: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::TrustRequestExpiry::get());
+			let mut requests = 0u64;
+			let mut scheduled = 0u64;
+			let mut blocks = 1u64;
+			for (requester, target) in TrustRequestList::<T>::iter_keys() {
+				requests += 1;
+				if TrustRequestExpiries::<T>::contains_key(&requester, &target) {
+					continue;
+				}
+				let pair = (requester.clone(), target.clone());
+				while ExpiringTrustRequests::<T>::try_append(expires_at, &pair).is_err() {
+					expires_at.saturating_inc();
+					blocks += 1;
+				}
+				TrustRequestExpiries::<T>::insert(&requester, &target, expires_at);
+				scheduled += 1;
+			}
+			T::DbWeight::get().reads_writes(
+				requests.saturating_mul(2).saturating_add(blocks),
+				scheduled.saturating_mul(2),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((TrustRequestList::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				TrustRequestList::<T>::iter_keys().count() as u32 == count,
+				"trust requests were lost"
+			);
+			if T::MaxExpiringTrustRequestsPerBlock::get() == 0 {
+				return Ok(());
+			}
+			for (requester, target) in TrustRequestList::<T>::iter_keys() {
+				let expires_at = TrustRequestExpiries::<T>::get(&requester, &target)
+					.ok_or("trust request has no expiry")?;
+				ensure!(
+					ExpiringTrustRequests::<T>::get(expires_at).contains(&(requester, target)),
+					"trust request expiry is not scheduled"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrates the trust pallet from storage version 2 to 3.
+	pub type MigrateV2ToV3<T> = VersionedMigration<
+		2,
+		3,
+		InnerMigrateV2ToV3<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
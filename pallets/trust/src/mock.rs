@@ -1,7 +1,7 @@
 use crate as pallet_trust;
 use frame_support::{derive_impl, parameter_types};
 use frame_system as system;
-use sp_core::{ConstU32, H256};
+use sp_core::{ConstU32, ConstU64, ConstU8, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
@@ -54,6 +54,11 @@ impl pallet_trust::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxTrustParameterSize = ConstU32<1024>;
+	type MaxTrustLevel = ConstU8<100>;
+	type MaxTrustContextSize = ConstU32<32>;
+	type MaxTrustPathDepth = ConstU32<4>;
+	type TrustRequestExpiry = ConstU64<10>;
+	type MaxExpiringTrustRequestsPerBlock = ConstU32<2>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,5 +1,11 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations, mock::*, CurrentIssued, CurrentRequests, CurrentRevoked, Error, TrustCount,
+	TrustCounts, TrustIssuance, TrustRecord, TrustRequestList, TrustRevocation, TrustedBy,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+};
 use sp_core::ConstU32;
 use sp_runtime::BoundedVec;
 
@@ -20,8 +26,8 @@ fn test_set_trust_parameter() {
 fn issue_trust() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::TrustIssued(1, 1).into());
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 1, 100, None));
+		System::assert_last_event(crate::Event::TrustIssued(1, 1, 100).into());
 		assert_eq!(TrustModule::get_current_trust_count(), 1);
 	});
 }
@@ -30,12 +36,12 @@ fn issue_trust() {
 fn issue_trust_error() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::TrustIssued(1, 1).into());
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 1, 100, None));
+		System::assert_last_event(crate::Event::TrustIssued(1, 1, 100).into());
 		assert_eq!(TrustModule::get_current_trust_count(), 1);
 
 		assert_noop!(
-			TrustModule::issue_trust(RuntimeOrigin::signed(1), 1),
+			TrustModule::issue_trust(RuntimeOrigin::signed(1), 1, 100, None),
 			Error::<Test>::TrustExists
 		);
 	});
@@ -98,8 +104,8 @@ fn cancel_request_trust_error() {
 fn remove_trust() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::TrustIssued(1, 1).into());
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 1, 100, None));
+		System::assert_last_event(crate::Event::TrustIssued(1, 1, 100).into());
 		assert_eq!(TrustModule::get_current_trust_count(), 1);
 
 		assert_ok!(TrustModule::remove_trust(RuntimeOrigin::signed(1), 1));
@@ -112,8 +118,8 @@ fn remove_trust() {
 fn remove_trust_no_failure() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::TrustIssued(1, 1).into());
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 1, 100, None));
+		System::assert_last_event(crate::Event::TrustIssued(1, 1, 100).into());
 		assert_eq!(TrustModule::get_current_trust_count(), 1);
 
 		assert_ok!(TrustModule::remove_trust(RuntimeOrigin::signed(1), 1));
@@ -194,3 +200,364 @@ fn remove_revoked_trust_raises_error() {
 		);
 	});
 }
+
+fn context(tag: &str) -> BoundedVec<u8, ConstU32<32>> {
+	BoundedVec::try_from(tag.as_bytes().to_vec()).unwrap()
+}
+
+#[test]
+fn issue_trust_with_level_and_context() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TrustModule::issue_trust(RuntimeOrigin::signed(1), 2, 101, None),
+			Error::<Test>::InvalidTrustLevel
+		);
+		assert_ok!(TrustModule::issue_trust(
+			RuntimeOrigin::signed(1),
+			2,
+			30,
+			Some(context("rust"))
+		));
+		System::assert_last_event(crate::Event::TrustIssued(1, 2, 30).into());
+		assert_eq!(
+			TrustModule::get_trust_issuance(1, 2),
+			Some(TrustRecord { level: 30, context: Some(context("rust")) })
+		);
+		assert_eq!(TrustModule::trust_level(&1, &2), Some(30));
+		assert_eq!(TrustModule::trust_level(&2, &1), None);
+	});
+}
+
+#[test]
+fn update_trust_level() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TrustModule::update_trust_level(RuntimeOrigin::signed(1), 2, 80, None),
+			Error::<Test>::TrustNotFound
+		);
+		assert_ok!(TrustModule::issue_trust(
+			RuntimeOrigin::signed(1),
+			2,
+			30,
+			Some(context("rust"))
+		));
+		assert_noop!(
+			TrustModule::update_trust_level(RuntimeOrigin::signed(1), 2, 101, None),
+			Error::<Test>::InvalidTrustLevel
+		);
+
+		assert_ok!(TrustModule::update_trust_level(RuntimeOrigin::signed(1), 2, 80, None));
+		System::assert_last_event(crate::Event::TrustLevelUpdated(1, 2, 80).into());
+		assert_eq!(
+			TrustModule::get_trust_issuance(1, 2),
+			Some(TrustRecord { level: 80, context: None })
+		);
+		assert_eq!(TrustModule::get_current_trust_count(), 1);
+	});
+}
+
+#[test]
+fn migrate_trust_issuance_to_records() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<TrustModule>();
+		// Before levels existed, each issuance stored a snapshot of the global counter.
+		for (issuer, subject, snapshot) in [(1u64, 2u64, 0u32), (2, 3, 1)] {
+			frame_support::storage::unhashed::put(
+				&TrustIssuance::<Test>::hashed_key_for(issuer, subject),
+				&snapshot,
+			);
+		}
+
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TrustModule::on_chain_storage_version(), 1);
+		for (issuer, subject) in [(1, 2), (2, 3)] {
+			assert_eq!(
+				TrustModule::get_trust_issuance(issuer, subject),
+				Some(TrustRecord { level: 100, context: None })
+			);
+			assert!(TrustedBy::<Test>::contains_key(subject, issuer));
+		}
+	});
+}
+
+#[test]
+fn trusts_and_trusted_by() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2, 40, None));
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 3, 60, None));
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(4), 2, 80, None));
+
+		let mut trusts = TrustModule::trusts(&1);
+		trusts.sort();
+		assert_eq!(trusts, vec![(2, 40), (3, 60)]);
+		let mut trusted_by = TrustModule::trusted_by(&2);
+		trusted_by.sort();
+		assert_eq!(trusted_by, vec![(1, 40), (4, 80)]);
+
+		assert_ok!(TrustModule::remove_trust(RuntimeOrigin::signed(1), 2));
+		assert_eq!(TrustModule::trusted_by(&2), vec![(4, 80)]);
+		assert!(!TrustedBy::<Test>::contains_key(2, 1));
+	});
+}
+
+#[test]
+fn trust_path_follows_issued_trust() {
+	new_test_ext().execute_with(|| {
+		for (issuer, subject) in [(1, 2), (2, 3), (3, 4), (1, 5), (5, 4)] {
+			assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(issuer), subject, 100, None));
+		}
+
+		assert_eq!(TrustModule::trust_path(&1, &1, 0), Some(vec![1]));
+		assert_eq!(TrustModule::trust_path(&1, &4, 4), Some(vec![1, 5, 4]));
+		assert_eq!(TrustModule::trust_path(&1, &3, 4), Some(vec![1, 2, 3]));
+		// Trust is directional.
+		assert_eq!(TrustModule::trust_path(&4, &1, 4), None);
+	});
+}
+
+#[test]
+fn trust_path_skips_revoked_links() {
+	new_test_ext().execute_with(|| {
+		for (issuer, subject) in [(1, 2), (2, 3), (1, 4), (4, 5), (5, 3)] {
+			assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(issuer), subject, 100, None));
+		}
+		assert_ok!(TrustModule::revoke_trust(RuntimeOrigin::signed(2), 3));
+
+		assert_eq!(TrustModule::trust_path(&1, &3, 4), Some(vec![1, 4, 5, 3]));
+
+		assert_ok!(TrustModule::revoke_trust(RuntimeOrigin::signed(5), 3));
+		assert_eq!(TrustModule::trust_path(&1, &3, 4), None);
+	});
+}
+
+#[test]
+fn trust_path_respects_depth_limit() {
+	new_test_ext().execute_with(|| {
+		for issuer in 1..7u64 {
+			assert_ok!(TrustModule::issue_trust(
+				RuntimeOrigin::signed(issuer),
+				issuer + 1,
+				100,
+				None
+			));
+		}
+
+		assert_eq!(TrustModule::trust_path(&1, &4, 2), None);
+		assert_eq!(TrustModule::trust_path(&1, &4, 3), Some(vec![1, 2, 3, 4]));
+		// MaxTrustPathDepth is 4 in the mock, whatever the caller asks for.
+		assert_eq!(TrustModule::trust_path(&1, &5, 10), Some(vec![1, 2, 3, 4, 5]));
+		assert_eq!(TrustModule::trust_path(&1, &6, 10), None);
+	});
+}
+
+#[test]
+fn accept_trust_request() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TrustModule::request_trust(RuntimeOrigin::signed(1), 2));
+
+		assert_ok!(TrustModule::accept_trust_request(
+			RuntimeOrigin::signed(2),
+			1,
+			60,
+			Some(context("rust"))
+		));
+		System::assert_has_event(crate::Event::TrustIssued(2, 1, 60).into());
+		System::assert_last_event(crate::Event::TrustRequestAccepted(1, 2, 60).into());
+		assert_eq!(
+			TrustModule::get_trust_issuance(2, 1),
+			Some(TrustRecord { level: 60, context: Some(context("rust")) })
+		);
+		assert_eq!(TrustModule::get_current_trust_count(), 1);
+		assert_eq!(TrustModule::get_trust_request(1, 2), None);
+		assert_eq!(TrustModule::trust_request_expiry(1, 2), None);
+		assert_eq!(TrustModule::get_current_trust_requests(), 0);
+	});
+}
+
+#[test]
+fn accept_trust_request_error() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TrustModule::accept_trust_request(RuntimeOrigin::signed(2), 1, 60, None),
+			Error::<Test>::TrustRequestNotFound
+		);
+
+		assert_ok!(TrustModule::request_trust(RuntimeOrigin::signed(1), 2));
+		assert_noop!(
+			TrustModule::accept_trust_request(RuntimeOrigin::signed(2), 1, 101, None),
+			Error::<Test>::InvalidTrustLevel
+		);
+
+		// Accepting fails as a whole when trust was already issued, leaving the request open.
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(2), 1, 30, None));
+		assert_noop!(
+			TrustModule::accept_trust_request(RuntimeOrigin::signed(2), 1, 60, None),
+			Error::<Test>::TrustExists
+		);
+		assert!(TrustModule::get_trust_request(1, 2).is_some());
+	});
+}
+
+#[test]
+fn decline_trust_request() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TrustModule::request_trust(RuntimeOrigin::signed(1), 2));
+		assert_noop!(
+			TrustModule::decline_trust_request(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::TrustRequestNotFound
+		);
+
+		assert_ok!(TrustModule::decline_trust_request(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(crate::Event::TrustRequestDeclined(1, 2).into());
+		assert_eq!(TrustModule::get_trust_request(1, 2), None);
+		assert_eq!(TrustModule::get_trust_issuance(2, 1), None);
+		assert_eq!(TrustModule::get_current_trust_requests(), 0);
+	});
+}
+
+#[test]
+fn trust_requests_expire() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TrustModule::request_trust(RuntimeOrigin::signed(1), 2));
+		assert_ok!(TrustModule::request_trust(RuntimeOrigin::signed(3), 2));
+		assert_eq!(TrustModule::trust_request_expiry(1, 2), Some(11));
+
+		// A request cancelled and placed again carries the later expiry.
+		assert_ok!(TrustModule::cancel_trust_request(RuntimeOrigin::signed(3), 2));
+		System::set_block_number(5);
+		assert_ok!(TrustModule::request_trust(RuntimeOrigin::signed(3), 2));
+
+		System::set_block_number(11);
+		TrustModule::on_initialize(11);
+		System::assert_last_event(crate::Event::TrustRequestExpired(1, 2).into());
+		assert_eq!(TrustModule::get_trust_request(1, 2), None);
+		assert_eq!(TrustModule::trust_request_expiry(1, 2), None);
+		assert!(TrustModule::get_trust_request(3, 2).is_some());
+		assert_eq!(TrustModule::get_current_trust_requests(), 1);
+		assert!(TrustModule::expiring_trust_requests(11).is_empty());
+
+		System::set_block_number(15);
+		TrustModule::on_initialize(15);
+		System::assert_last_event(crate::Event::TrustRequestExpired(3, 2).into());
+		assert_eq!(TrustModule::get_current_trust_requests(), 0);
+	});
+}
+
+#[test]
+fn too_many_expiring_trust_requests() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TrustModule::request_trust(RuntimeOrigin::signed(1), 4));
+		assert_ok!(TrustModule::request_trust(RuntimeOrigin::signed(2), 4));
+		assert_noop!(
+			TrustModule::request_trust(RuntimeOrigin::signed(3), 4),
+			Error::<Test>::TooManyExpiringTrustRequests
+		);
+
+		System::set_block_number(2);
+		assert_ok!(TrustModule::request_trust(RuntimeOrigin::signed(3), 4));
+	});
+}
+
+#[test]
+fn trust_counts_follow_trust_actions() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 2, 100, None));
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(1), 3, 100, None));
+		assert_ok!(TrustModule::issue_trust(RuntimeOrigin::signed(3), 2, 100, None));
+		assert_ok!(TrustModule::revoke_trust(RuntimeOrigin::signed(1), 4));
+
+		assert_eq!(TrustModule::trust_counts(1), TrustCount { issued: 2, received: 0, revoked: 1 });
+		assert_eq!(TrustModule::trust_counts(2), TrustCount { issued: 0, received: 2, revoked: 0 });
+		assert_eq!(TrustModule::trust_counts(3), TrustCount { issued: 1, received: 1, revoked: 0 });
+		assert_eq!(TrustModule::get_current_trust_count(), 3);
+		assert_eq!(TrustModule::get_current_non_trust_count(), 1);
+
+		assert_ok!(TrustModule::remove_trust(RuntimeOrigin::signed(1), 2));
+		assert_ok!(TrustModule::remove_revoked_trust(RuntimeOrigin::signed(1), 4));
+		assert_eq!(TrustModule::trust_counts(1), TrustCount { issued: 1, received: 0, revoked: 0 });
+		assert_eq!(TrustModule::get_current_trust_count(), 2);
+		assert_eq!(TrustModule::get_current_non_trust_count(), 0);
+
+		// Accounts left with no trust activity have their entry dropped.
+		assert_ok!(TrustModule::remove_trust(RuntimeOrigin::signed(1), 3));
+		assert!(!TrustCounts::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn migrate_trust_counters() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<TrustModule>();
+		for (issuer, subject) in [(1u64, 2u64), (1, 3), (3, 2)] {
+			TrustIssuance::<Test>::insert(
+				issuer,
+				subject,
+				TrustRecord { level: 100, context: None },
+			);
+		}
+		// Revocations and requests used to store a snapshot of their global counter.
+		for (issuer, subject, snapshot) in [(1u64, 4u64, 7u32), (2, 4, 7)] {
+			frame_support::storage::unhashed::put(
+				&TrustRevocation::<Test>::hashed_key_for(issuer, subject),
+				&snapshot,
+			);
+		}
+		frame_support::storage::unhashed::put(
+			&TrustRequestList::<Test>::hashed_key_for(5, 1),
+			&3u32,
+		);
+		// Counters that drifted from the maps.
+		CurrentIssued::<Test>::put(9);
+		CurrentRevoked::<Test>::put(0);
+		CurrentRequests::<Test>::put(4);
+
+		migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TrustModule::on_chain_storage_version(), 2);
+		assert_eq!(TrustModule::get_current_trust_count(), 3);
+		assert_eq!(TrustModule::get_current_non_trust_count(), 2);
+		assert_eq!(TrustModule::get_current_trust_requests(), 1);
+		assert_eq!(TrustModule::get_non_trust_issuance(1, 4), Some(()));
+		assert_eq!(TrustModule::get_trust_request(5, 1), Some(()));
+		assert_eq!(TrustModule::trust_counts(1), TrustCount { issued: 2, received: 0, revoked: 1 });
+		assert_eq!(TrustModule::trust_counts(2), TrustCount { issued: 0, received: 2, revoked: 1 });
+		assert_eq!(TrustModule::trust_counts(3), TrustCount { issued: 1, received: 1, revoked: 0 });
+		assert!(!TrustCounts::<Test>::contains_key(5));
+	});
+}
+
+#[test]
+fn migrate_trust_request_expiries() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		StorageVersion::new(2).put::<TrustModule>();
+		// Requests placed before requests could expire have no expiry scheduled.
+		for (requester, target) in [(1u64, 2u64), (1, 3), (2, 3)] {
+			TrustRequestList::<Test>::insert(requester, target, ());
+		}
+		CurrentRequests::<Test>::put(3);
+
+		migrations::v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TrustModule::on_chain_storage_version(), 3);
+		// Two requests fit in block 11, so the third spills over into block 12.
+		assert_eq!(TrustModule::expiring_trust_requests(11).len(), 2);
+		assert_eq!(TrustModule::expiring_trust_requests(12).len(), 1);
+		for (requester, target) in [(1, 2), (1, 3), (2, 3)] {
+			assert!(TrustModule::trust_request_expiry(requester, target).is_some());
+		}
+
+		TrustModule::on_initialize(11);
+		TrustModule::on_initialize(12);
+		assert_eq!(TrustModule::get_current_trust_requests(), 0);
+		assert_eq!(TrustRequestList::<Test>::iter_keys().count(), 0);
+	});
+}
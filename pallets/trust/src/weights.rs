@@ -41,6 +41,10 @@ pub trait WeightInfo {
 	fn remove_revoked_trust_heavy_storage() -> Weight;
 	fn cancel_trust_request() -> Weight;
 	fn cancel_trust_request_heavy_storage() -> Weight;
+	fn update_trust_level() -> Weight;
+	fn accept_trust_request() -> Weight;
+	fn decline_trust_request() -> Weight;
+	fn expire_trust_requests(n: u32) -> Weight;
 }
 
 /// Weights for pallet_trust using the Substrate node and recommended hardware.
@@ -53,124 +57,248 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(9_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
-	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(100), added: 2575, mode:
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606, mode:
 	/// `MaxEncodedLen`) Storage: `Trust::CurrentIssued` (r:1 w:1)
 	/// Proof: `Trust::CurrentIssued` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode:
 	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustedBy` (r:0 w:1)
+	/// Proof: `Trust::TrustedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:2 w:2)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn issue_trust() -> Weight {
-		Weight::from_parts(13_000_000, 3565)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(13_000_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
-	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(100), added: 2575, mode:
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606, mode:
 	/// `MaxEncodedLen`) Storage: `Trust::CurrentIssued` (r:1 w:1)
 	/// Proof: `Trust::CurrentIssued` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode:
 	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustedBy` (r:0 w:1)
+	/// Proof: `Trust::TrustedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:2 w:2)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn issue_trust_repeatedly() -> Weight {
-		Weight::from_parts(26_000_000, 3565)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(26_000_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Trust::TrustRevocation` (r:1 w:1)
-	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRevoked` (r:1 w:1)
 	/// Proof: `Trust::CurrentRevoked` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:1 w:1)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn revoke_trust() -> Weight {
-		Weight::from_parts(13_000_000, 3565)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(13_000_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Trust::TrustRevocation` (r:1 w:1)
-	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRevoked` (r:1 w:1)
 	/// Proof: `Trust::CurrentRevoked` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:1 w:1)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn revoke_trust_from_heavy_storage() -> Weight {
-		Weight::from_parts(58_000_000, 3565)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(58_000_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
-	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(100), added: 2575, mode:
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606, mode:
 	/// `MaxEncodedLen`) Storage: `Trust::CurrentIssued` (r:1 w:1)
 	/// Proof: `Trust::CurrentIssued` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode:
 	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustedBy` (r:0 w:1)
+	/// Proof: `Trust::TrustedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:2 w:2)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn remove_trust() -> Weight {
-		Weight::from_parts(17_000_000, 3565)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(17_000_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
-	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(100), added: 2575, mode:
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606, mode:
 	/// `MaxEncodedLen`) Storage: `Trust::CurrentIssued` (r:1 w:1)
 	/// Proof: `Trust::CurrentIssued` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode:
 	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustedBy` (r:0 w:1)
+	/// Proof: `Trust::TrustedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:2 w:2)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn remove_trust_from_heavy_storage() -> Weight {
-		Weight::from_parts(26_000_000, 3565)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(26_000_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
-	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRequests` (r:1 w:1)
 	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::ExpiringTrustRequests` (r:1 w:1)
+	/// Proof: `Trust::ExpiringTrustRequests` (`max_values`: None, `max_size`: Some(4110), added:
+	/// 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
 	fn request_trust() -> Weight {
-		Weight::from_parts(13_000_000, 3565)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(13_000_000, 7575)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
-	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRequests` (r:1 w:1)
 	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::ExpiringTrustRequests` (r:1 w:1)
+	/// Proof: `Trust::ExpiringTrustRequests` (`max_values`: None, `max_size`: Some(4110), added:
+	/// 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
 	fn request_trust_repeatedly() -> Weight {
-		Weight::from_parts(23_000_000, 3565)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(23_000_000, 7575)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Trust::TrustRevocation` (r:1 w:1)
-	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRevoked` (r:1 w:1)
 	/// Proof: `Trust::CurrentRevoked` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:1 w:1)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn remove_revoked_trust() -> Weight {
-		Weight::from_parts(16_000_000, 3565)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(16_000_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Trust::TrustRevocation` (r:1 w:1)
-	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRevoked` (r:1 w:1)
 	/// Proof: `Trust::CurrentRevoked` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:1 w:1)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn remove_revoked_trust_heavy_storage() -> Weight {
-		Weight::from_parts(26_000_000, 3565)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(26_000_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
-	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRequests` (r:1 w:1)
 	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
 	fn cancel_trust_request() -> Weight {
-		Weight::from_parts(15_000_000, 3565)
+		Weight::from_parts(15_000_000, 3561)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
-	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRequests` (r:1 w:1)
 	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
 	fn cancel_trust_request_heavy_storage() -> Weight {
-		Weight::from_parts(24_000_000, 3565)
+		Weight::from_parts(24_000_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606,
+	/// mode: `MaxEncodedLen`)
+	fn update_trust_level() -> Weight {
+		Weight::from_parts(14_000_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::CurrentRequests` (r:1 w:1)
+	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::CurrentIssued` (r:1 w:1)
+	/// Proof: `Trust::CurrentIssued` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustedBy` (r:0 w:1)
+	/// Proof: `Trust::TrustedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:2 w:2)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
+	fn accept_trust_request() -> Weight {
+		Weight::from_parts(24_000_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::CurrentRequests` (r:1 w:1)
+	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
+	fn decline_trust_request() -> Weight {
+		Weight::from_parts(15_000_000, 3561)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Trust::ExpiringTrustRequests` (r:1 w:1)
+	/// Proof: `Trust::ExpiringTrustRequests` (`max_values`: None, `max_size`: Some(4110), added:
+	/// 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:64 w:64)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestList` (r:64 w:64)
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::CurrentRequests` (r:64 w:64)
+	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn expire_trust_requests(n: u32) -> Weight {
+		Weight::from_parts(4_000_000, 7575)
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2575).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
 	}
 }
 
@@ -187,171 +315,311 @@ impl WeightInfo for () {
 		Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
-	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(100), added: 2575, mode:
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606, mode:
 	/// `MaxEncodedLen`) Storage: `Trust::CurrentIssued` (r:1 w:1)
 	/// Proof: `Trust::CurrentIssued` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode:
 	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustedBy` (r:0 w:1)
+	/// Proof: `Trust::TrustedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:2 w:2)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn issue_trust() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
-		//  Estimated: `3565`
+		//  Estimated: `3596`
 		// Minimum execution time: 13_000_000 picoseconds.
-		Weight::from_parts(13_000_000, 3565)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(13_000_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
-	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(100), added: 2575, mode:
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606, mode:
 	/// `MaxEncodedLen`) Storage: `Trust::CurrentIssued` (r:1 w:1)
 	/// Proof: `Trust::CurrentIssued` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode:
 	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustedBy` (r:0 w:1)
+	/// Proof: `Trust::TrustedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:2 w:2)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn issue_trust_repeatedly() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1158`
-		//  Estimated: `3565`
+		//  Estimated: `3596`
 		// Minimum execution time: 19_000_000 picoseconds.
-		Weight::from_parts(26_000_000, 3565)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(26_000_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Trust::TrustRevocation` (r:1 w:1)
-	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRevoked` (r:1 w:1)
 	/// Proof: `Trust::CurrentRevoked` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:1 w:1)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn revoke_trust() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
-		//  Estimated: `3565`
+		//  Estimated: `3561`
 		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(13_000_000, 3565)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(13_000_000, 3561)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Trust::TrustRevocation` (r:1 w:1)
-	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRevoked` (r:1 w:1)
 	/// Proof: `Trust::CurrentRevoked` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:1 w:1)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn revoke_trust_from_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2151`
-		//  Estimated: `3565`
+		//  Estimated: `3561`
 		// Minimum execution time: 49_000_000 picoseconds.
-		Weight::from_parts(58_000_000, 3565)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(58_000_000, 3561)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
-	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(100), added: 2575, mode:
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606, mode:
 	/// `MaxEncodedLen`) Storage: `Trust::CurrentIssued` (r:1 w:1)
 	/// Proof: `Trust::CurrentIssued` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode:
 	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustedBy` (r:0 w:1)
+	/// Proof: `Trust::TrustedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:2 w:2)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn remove_trust() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `157`
-		//  Estimated: `3565`
+		//  Estimated: `3596`
 		// Minimum execution time: 15_000_000 picoseconds.
-		Weight::from_parts(17_000_000, 3565)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(17_000_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
-	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(100), added: 2575, mode:
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606, mode:
 	/// `MaxEncodedLen`) Storage: `Trust::CurrentIssued` (r:1 w:1)
 	/// Proof: `Trust::CurrentIssued` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode:
 	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustedBy` (r:0 w:1)
+	/// Proof: `Trust::TrustedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:2 w:2)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn remove_trust_from_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1263`
-		//  Estimated: `3565`
+		//  Estimated: `3596`
 		// Minimum execution time: 20_000_000 picoseconds.
-		Weight::from_parts(26_000_000, 3565)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(26_000_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
-	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRequests` (r:1 w:1)
 	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::ExpiringTrustRequests` (r:1 w:1)
+	/// Proof: `Trust::ExpiringTrustRequests` (`max_values`: None, `max_size`: Some(4110), added:
+	/// 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
 	fn request_trust() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
-		//  Estimated: `3565`
+		//  Estimated: `7575`
 		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(13_000_000, 3565)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(13_000_000, 7575)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
-	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRequests` (r:1 w:1)
 	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::ExpiringTrustRequests` (r:1 w:1)
+	/// Proof: `Trust::ExpiringTrustRequests` (`max_values`: None, `max_size`: Some(4110), added:
+	/// 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
 	fn request_trust_repeatedly() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1156`
-		//  Estimated: `3565`
+		//  Estimated: `7575`
 		// Minimum execution time: 18_000_000 picoseconds.
-		Weight::from_parts(23_000_000, 3565)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(23_000_000, 7575)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Trust::TrustRevocation` (r:1 w:1)
-	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRevoked` (r:1 w:1)
 	/// Proof: `Trust::CurrentRevoked` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:1 w:1)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn remove_revoked_trust() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `157`
-		//  Estimated: `3565`
+		//  Estimated: `3561`
 		// Minimum execution time: 15_000_000 picoseconds.
-		Weight::from_parts(16_000_000, 3565)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(16_000_000, 3561)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Trust::TrustRevocation` (r:1 w:1)
-	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRevocation` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRevoked` (r:1 w:1)
 	/// Proof: `Trust::CurrentRevoked` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:1 w:1)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
 	fn remove_revoked_trust_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1263`
-		//  Estimated: `3565`
+		//  Estimated: `3561`
 		// Minimum execution time: 21_000_000 picoseconds.
-		Weight::from_parts(26_000_000, 3565)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(26_000_000, 3561)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
-	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRequests` (r:1 w:1)
 	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
 	fn cancel_trust_request() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `155`
-		//  Estimated: `3565`
+		//  Estimated: `3561`
 		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(15_000_000, 3565)
+		Weight::from_parts(15_000_000, 3561)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
-	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(100), added: 2575,
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
 	/// mode: `MaxEncodedLen`) Storage: `Trust::CurrentRequests` (r:1 w:1)
 	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
 	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
 	fn cancel_trust_request_heavy_storage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1156`
-		//  Estimated: `3565`
+		//  Estimated: `3561`
 		// Minimum execution time: 19_000_000 picoseconds.
-		Weight::from_parts(24_000_000, 3565)
+		Weight::from_parts(24_000_000, 3561)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606,
+	/// mode: `MaxEncodedLen`)
+	fn update_trust_level() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `131`
+		//  Estimated: `3596`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::CurrentRequests` (r:1 w:1)
+	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustIssuance` (r:1 w:1)
+	/// Proof: `Trust::TrustIssuance` (`max_values`: None, `max_size`: Some(131), added: 2606,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::CurrentIssued` (r:1 w:1)
+	/// Proof: `Trust::CurrentIssued` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustedBy` (r:0 w:1)
+	/// Proof: `Trust::TrustedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode:
+	/// `MaxEncodedLen`)
+	/// Storage: `Trust::TrustCounts` (r:2 w:2)
+	/// Proof: `Trust::TrustCounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode:
+	/// `MaxEncodedLen`)
+	fn accept_trust_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3596`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Trust::TrustRequestList` (r:1 w:1)
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::CurrentRequests` (r:1 w:1)
+	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:0 w:1)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
+	fn decline_trust_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3561`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3561)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Trust::ExpiringTrustRequests` (r:1 w:1)
+	/// Proof: `Trust::ExpiringTrustRequests` (`max_values`: None, `max_size`: Some(4110), added:
+	/// 6585, mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestExpiries` (r:64 w:64)
+	/// Proof: `Trust::TrustRequestExpiries` (`max_values`: None, `max_size`: Some(100), added:
+	/// 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Trust::TrustRequestList` (r:64 w:64)
+	/// Proof: `Trust::TrustRequestList` (`max_values`: None, `max_size`: Some(96), added: 2571,
+	/// mode: `MaxEncodedLen`)
+	/// Storage: `Trust::CurrentRequests` (r:64 w:64)
+	/// Proof: `Trust::CurrentRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499,
+	/// mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn expire_trust_requests(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `7575`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(4_000_000, 7575)
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2575).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
 	}
 }
@@ -109,12 +109,13 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
-	//   the compatible custom types.
-	spec_version: 100,
+	// This value started at 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
+	//   the compatible custom types. It must be bumped for every upgrade so the runtime's
+	//   `Migrations` run.
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -157,9 +158,23 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 42;
 
 	pub const IdentityMaxSize: u32 = 1024;
+    pub const IdentityMaxIdentitiesPerOwner: u32 = 100;
+    pub const IdentityMaxTraitOperations: u32 = 64;
+    pub const IdentityMaxRecoveryAccounts: u32 = 10;
     pub const KeystoreMaxSize: u32 = 1024;
+    pub const KeystoreMaxKeyHistory: u32 = 16;
+    pub const KeystoreMaxExpiringKeysPerBlock: u32 = 64;
+    pub const KeystoreMaxEncryptionKeys: u32 = 256;
+    pub const KeystoreMaxKeyDelegates: u32 = 16;
+    pub const KeystoreMaxGuardians: u32 = 8;
+    pub const KeystoreRecoveryDelay: BlockNumber = 2 * DAYS;
     pub const SignalMaxSize: u32 = 1024;
     pub const TrustParameterMaxSize: u32 = 1024;
+    pub const TrustMaxLevel: u8 = 100;
+    pub const TrustMaxContextSize: u32 = 32;
+    pub const TrustMaxPathDepth: u32 = 6;
+    pub const TrustRequestExpiry: BlockNumber = 14 * DAYS;
+    pub const TrustMaxExpiringRequestsPerBlock: u32 = 64;
     pub const InfostratusMaxSize: u32 = 1024;
 
     pub const SignalLockIdentifier: [u8; 8] = *b"fnlsignl";
@@ -167,10 +182,16 @@ parameter_types! {
 
     pub const CertificateLockIdentifier: [u8; 8] = *b"fnlcertf";
     pub const CertificateLockPrice: u32 = 100;
+    pub const CertificateMaxClaimSize: u32 = 1024;
+    pub const CertificateMaxChainDepth: u32 = 5;
 
     pub const InfostratusLockIdentifier: [u8; 8] = *b"infstrts";
     pub const InfostratusLockPrice: u32 = 100;
 
+    pub const IdentityDepositPerByte: Balance = 10;
+    pub const IdentityRecoveryDeposit: Balance = 1_000;
+    pub const KeystoreDepositPerByte: Balance = 10;
+
     pub const MinAuthorities: u32 = 2;
 
     pub const Period: u32 = 2 * MINUTES;
@@ -277,12 +298,25 @@ impl pallet_keystore::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_keystore::weights::SubstrateWeight<Runtime>;
     type MaxSize = KeystoreMaxSize;
+    type MaxKeyHistory = KeystoreMaxKeyHistory;
+    type MaxExpiringKeysPerBlock = KeystoreMaxExpiringKeysPerBlock;
+    type MaxEncryptionKeys = KeystoreMaxEncryptionKeys;
+    type MaxKeyDelegates = KeystoreMaxKeyDelegates;
+    type Currency = Balances;
+    type DepositPerByte = KeystoreDepositPerByte;
+    type MaxGuardians = KeystoreMaxGuardians;
+    type RecoveryDelay = KeystoreRecoveryDelay;
 }
 
 impl pallet_trust::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_trust::weights::SubstrateWeight<Runtime>;
     type MaxTrustParameterSize = TrustParameterMaxSize;
+    type MaxTrustLevel = TrustMaxLevel;
+    type MaxTrustContextSize = TrustMaxContextSize;
+    type MaxTrustPathDepth = TrustMaxPathDepth;
+    type TrustRequestExpiry = TrustRequestExpiry;
+    type MaxExpiringTrustRequestsPerBlock = TrustMaxExpiringRequestsPerBlock;
 }
 
 impl pallet_signal::Config for Runtime {
@@ -298,6 +332,13 @@ impl pallet_identity::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
     type MaxSize = IdentityMaxSize;
+    type Currency = Balances;
+    type DepositPerByte = IdentityDepositPerByte;
+    type MaxIdentitiesPerOwner = IdentityMaxIdentitiesPerOwner;
+    type MaxTraitOperations = IdentityMaxTraitOperations;
+    type PrivilegedOrigin = EnsureRoot<AccountId>;
+    type MaxRecoveryAccounts = IdentityMaxRecoveryAccounts;
+    type RecoveryDeposit = IdentityRecoveryDeposit;
 }
 
 impl pallet_certificate::Config for Runtime {
@@ -306,6 +347,9 @@ impl pallet_certificate::Config for Runtime {
     type Currency = Balances;
     type LockId = CertificateLockIdentifier;
     type LockPrice = CertificateLockPrice;
+    type MaxClaimSize = CertificateMaxClaimSize;
+    type PrivilegedOrigin = EnsureRoot<AccountId>;
+    type MaxChainDepth = CertificateMaxChainDepth;
 }
 
 impl pallet_infostratus::Config for Runtime {
@@ -420,7 +464,15 @@ pub type SignedExtra = (
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_trust::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_trust::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_trust::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_identity::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_certificate::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_certificate::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_keystore::migrations::v1::MigrateV0ToV1<Runtime>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -577,6 +629,73 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_keystore_runtime_api::KeystoreApi<Block, AccountId, BlockNumber> for Runtime {
+		fn keys(account: AccountId) -> Vec<pallet_keystore_runtime_api::KeyDetails<BlockNumber>> {
+			Keystore::keys_of(&account)
+		}
+
+		fn encryption_key(
+			account: AccountId,
+		) -> Option<(u32, pallet_keystore_runtime_api::EncryptionKeyRecord<BlockNumber>)> {
+			Keystore::current_encryption_key(&account)
+		}
+
+		fn is_key_valid(account: AccountId, fingerprint: Vec<u8>) -> bool {
+			Keystore::is_key_valid(&account, &fingerprint)
+		}
+	}
+
+	impl pallet_identity_runtime_api::IdentityApi<Block, AccountId> for Runtime {
+		fn identities_of(account: AccountId) -> Vec<u32> {
+			Identity::identities_by_owner(&account).into_inner()
+		}
+
+		fn trait_attesters(identity_id: u32, key: Vec<u8>) -> Vec<AccountId> {
+			Identity::trait_attesters(identity_id, &key)
+		}
+
+		fn verify_trait_commitment(
+			identity_id: u32,
+			key: Vec<u8>,
+			value: Vec<u8>,
+			salt: Vec<u8>,
+		) -> bool {
+			Identity::verify_trait_commitment(identity_id, &key, &value, &salt)
+		}
+	}
+
+	impl pallet_certificate_runtime_api::CertificateApi<Block, AccountId, BlockNumber> for Runtime {
+		fn certificates_issued_by(
+			account: AccountId,
+		) -> Vec<pallet_certificate_runtime_api::CertificateInfo<AccountId, BlockNumber>> {
+			Certificate::certificates_issued_by(&account)
+		}
+
+		fn certificates_issued_to(
+			account: AccountId,
+		) -> Vec<pallet_certificate_runtime_api::CertificateInfo<AccountId, BlockNumber>> {
+			Certificate::certificates_issued_to(&account)
+		}
+
+		fn is_certificate_valid(issuer: AccountId, recipient: AccountId) -> bool {
+			Certificate::is_certificate_valid(&issuer, &recipient)
+		}
+	}
+
+	impl pallet_trust_runtime_api::TrustApi<Block, AccountId> for Runtime {
+		fn trusts(account: AccountId) -> Vec<(AccountId, u8)> {
+			Trust::trusts(&account)
+		}
+
+		fn trusted_by(account: AccountId) -> Vec<(AccountId, u8)> {
+			Trust::trusted_by(&account)
+		}
+
+		fn trust_path(from: AccountId, to: AccountId, max_depth: u32) -> Option<Vec<AccountId>> {
+			Trust::trust_path(&from, &to, max_depth)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,